@@ -84,7 +84,7 @@ fn main() {
     let iterations = 1; // Single iteration for debugging
 
     println!("Running {} simulations...", iterations);
-    let results = simulation::run_monte_carlo(&players, &[encounter], iterations);
+    let results = simulation::run_monte_carlo(&players, &[encounter], iterations, 42);
 
     // This test is for debugging purposes, so we don't need extensive result processing
     // Just a single simulation trace.
@@ -2,18 +2,47 @@ use crate::context::{ActiveEffect, EffectType, TurnContext};
 use crate::dice;
 use crate::events::{Event, RollResult};
 use crate::model::{Action, AtkAction, Buff, BuffAction, DebuffAction, HealAction, TemplateAction};
-use crate::enums::{TargetType};
-use crate::rng;
-use rand::Rng; // Import Rng trait for gen_range
+use crate::enums::{AttackMode, TargetType};
+use crate::rng::{self, BattleRandom};
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 /// Event-driven action resolver that converts actions into events
 #[derive(Debug, Clone)]
 pub struct ActionResolver {
-    /// Random number generator for dice rolls
-    #[allow(dead_code)]
-    rng_seed: Option<u64>,
+    /// Explicit seeded RNG threaded through this resolver's own rolls, so an encounter built
+    /// `with_seed` can be re-run bit-for-bit. `None` falls back to the `rng` module's
+    /// thread-local state, for legacy call sites that never adopted an explicit seed.
+    battle_rng: Option<RefCell<BattleRandom>>,
+}
+
+/// Great Weapon Master/Sharpshooter-style tradeoff applied when `should_power_attack` opts in.
+pub(crate) const POWER_ATTACK_TO_HIT_PENALTY: f64 = -5.0;
+pub(crate) const POWER_ATTACK_DAMAGE_BONUS: f64 = 10.0;
+
+/// Chance to hit a `target_ac` with a `+to_hit_bonus` attack, clamped to the usual 5%-95% band
+/// (nat 1 always misses, nat 20 always hits).
+fn hit_chance(to_hit_bonus: f64, target_ac: f64) -> f64 {
+    let needed_roll = target_ac - to_hit_bonus;
+    if needed_roll <= 1.0 {
+        0.95
+    } else if needed_roll >= 20.0 {
+        0.05
+    } else {
+        (21.0 - needed_roll) / 20.0
+    }
+}
+
+/// Whether trading `POWER_ATTACK_TO_HIT_PENALTY` accuracy for `POWER_ATTACK_DAMAGE_BONUS` damage
+/// raises this attack's expected damage against `target_ac`, given its current `to_hit_bonus`
+/// and `avg_damage`. Used both to pick a live attack's mode and (in `crate::sorting`) to
+/// estimate a monster's realistic effective to-hit for Shield Wall ordering.
+pub(crate) fn should_power_attack(to_hit_bonus: f64, avg_damage: f64, target_ac: f64) -> bool {
+    let normal_dpr = avg_damage * hit_chance(to_hit_bonus, target_ac);
+    let power_dpr = (avg_damage + POWER_ATTACK_DAMAGE_BONUS)
+        * hit_chance(to_hit_bonus + POWER_ATTACK_TO_HIT_PENALTY, target_ac);
+    power_dpr > normal_dpr
 }
 
 /// Result of an attack roll
@@ -40,15 +69,69 @@ impl Default for ActionResolver {
 }
 
 impl ActionResolver {
-    /// Create a new action resolver
+    /// Create a new action resolver that rolls via the `rng` module's thread-local state
     pub fn new() -> Self {
-        Self { rng_seed: None }
+        Self { battle_rng: None }
     }
 
-    /// Create a new action resolver with a specific seed for reproducible results
+    /// Create a new action resolver with a specific root seed for reproducible results. Each
+    /// combatant's rolls are drawn from their own `BattleRandom` sub-stream, so this resolver's
+    /// rolls are independent of any thread-local seed set elsewhere.
     pub fn with_seed(seed: u64) -> Self {
         Self {
-            rng_seed: Some(seed),
+            battle_rng: Some(RefCell::new(BattleRandom::new(seed))),
+        }
+    }
+
+    /// The root seed this resolver was constructed with, if any, for recording on
+    /// `EncounterResult`/`TurnResult`.
+    pub fn root_seed(&self) -> Option<u64> {
+        self.battle_rng.as_ref().map(|rng| rng.borrow().root_seed())
+    }
+
+    /// Roll a d20 on `combatant_id`'s own stream if this resolver has a root seed, else fall
+    /// back to the thread-local `rng` module.
+    fn roll_d20_for(&self, combatant_id: &str) -> u32 {
+        match &self.battle_rng {
+            Some(battle_rng) => battle_rng.borrow_mut().roll_d20(combatant_id),
+            None => rng::roll_d20(),
+        }
+    }
+
+    /// Roll a d20 for `combatant_id`, treating advantage/disadvantage as a single concept
+    /// rather than inlined if/else branching over two separate rolls. Still draws each die
+    /// through `roll_d20_for` (not `dice::expr::eval`'s own sampling) so `rng::force_d20_rolls`
+    /// test scenarios keep seeing rolls in the same order.
+    fn roll_d20_with_advantage(&self, combatant_id: &str, advantage: bool, disadvantage: bool) -> u32 {
+        let first = self.roll_d20_for(combatant_id);
+        if advantage && !disadvantage {
+            first.max(self.roll_d20_for(combatant_id))
+        } else if disadvantage && !advantage {
+            first.min(self.roll_d20_for(combatant_id))
+        } else {
+            first
+        }
+    }
+
+    /// Evaluate a dice formula on `combatant_id`'s own stream if this resolver has a root seed,
+    /// else fall back to the thread-local `rng` module - the `dice::evaluate` counterpart to
+    /// `roll_d20_for`, so damage/modifier rolls stay on the same per-combatant substream as that
+    /// combatant's d20 rolls instead of desyncing against it.
+    fn evaluate_for(&self, combatant_id: &str, formula: &crate::model::DiceFormula, dice_multiplier: u32) -> f64 {
+        match &self.battle_rng {
+            Some(battle_rng) => dice::evaluate_with_rng(formula, dice_multiplier, battle_rng.borrow_mut().rng_for(combatant_id)),
+            None => dice::evaluate(formula, dice_multiplier),
+        }
+    }
+
+    /// Detailed counterpart to `evaluate_for` - see that function for why `combatant_id` is
+    /// threaded through.
+    fn evaluate_detailed_for(&self, combatant_id: &str, formula: &crate::model::DiceFormula, dice_multiplier: u32) -> RollResult {
+        match &self.battle_rng {
+            Some(battle_rng) => {
+                dice::evaluate_detailed_with_rng(formula, dice_multiplier, battle_rng.borrow_mut().rng_for(combatant_id))
+            }
+            None => dice::evaluate_detailed(formula, dice_multiplier),
         }
     }
 
@@ -89,9 +172,17 @@ impl ActionResolver {
                 None => continue, // No valid target, skip this attack
             };
 
-            // Perform attack roll
-            let attack_result = self.roll_attack(attack, context, actor_id, &target_id);
+            // Decide Normal vs. Power attack mode against THIS target before rolling, since the
+            // tradeoff depends on the target's current effective AC (buffs/debuffs included).
             let target_ac = self.get_target_ac(&target_id, context);
+            let mode = if should_power_attack(dice::average(&attack.to_hit), dice::average(&attack.dpr), target_ac) {
+                AttackMode::Power
+            } else {
+                AttackMode::Normal
+            };
+
+            // Perform attack roll
+            let attack_result = self.roll_attack(attack, context, actor_id, &target_id, mode);
 
             // Check for hit:
             // 1. Critical Hit (Nat 20) always hits
@@ -113,7 +204,7 @@ impl ActionResolver {
 
             if is_hit {
                 // Hit!
-                let (damage, damage_roll) = self.calculate_damage(attack, attack_result.is_critical, context, actor_id);
+                let (damage, damage_roll) = self.calculate_damage(attack, attack_result.is_critical, context, actor_id, mode);
 
                 let hit_event = Event::AttackHit {
                     attacker_id: actor_id.to_string(),
@@ -122,6 +213,7 @@ impl ActionResolver {
                     attack_roll: attack_result.roll_detail,
                     damage_roll,
                     target_ac,
+                    mode,
                 };
                 context.record_event(hit_event.clone());
                 events.push(hit_event);
@@ -137,8 +229,35 @@ impl ActionResolver {
                 events.extend(trigger_events);
 
                 // Apply damage through TurnContext (unified method) - handles event emission
+                let target_was_concentrating = context.is_concentrating(&target_id);
                 let damage_events = context.apply_damage(&target_id, damage, "Physical", actor_id); // Default to Physical, upgrade later
                 events.extend(damage_events);
+
+                // Concentration check: a hit that breaks concentration drops every buff the
+                // target was maintaining. The save is a CON save: it uses the target's
+                // `con_save_bonus` in place of its general save bonus (active buffs still
+                // apply), and falls back to the general save bonus when that is unset.
+                if target_was_concentrating {
+                    let save_dc = crate::context::TurnContext::concentration_save_dc(damage);
+                    let general_bonus = self.get_save_bonus(&target_id, context);
+                    let con_bonus = match context.get_combatant(&target_id) {
+                        Some(t) => match t.base_combatant.creature.con_save_bonus {
+                            Some(con) => general_bonus - t.base_combatant.creature.save_bonus + con,
+                            None => general_bonus,
+                        },
+                        None => general_bonus,
+                    };
+                    let save_total = self.roll_d20_for(&target_id) as f64 + con_bonus;
+                    let concentration_events = if save_total < save_dc {
+                        context.break_concentration(&target_id)
+                    } else {
+                        vec![Event::ConcentrationMaintained { caster_id: target_id.clone(), save_dc }]
+                    };
+                    for event in &concentration_events {
+                        context.record_event(event.clone());
+                    }
+                    events.extend(concentration_events);
+                }
             } else {
                 // Miss!
                 let miss_event = Event::AttackMissed {
@@ -146,6 +265,7 @@ impl ActionResolver {
                     target_id: target_id.clone(),
                     attack_roll: attack_result.roll_detail,
                     target_ac,
+                    mode,
                 };
                 context.record_event(miss_event.clone());
                 events.push(miss_event);
@@ -250,8 +370,7 @@ impl ActionResolver {
 
         for target_id in targets {
             // 1. Perform saving throw
-            let mut rng = rng::get_rng();
-            let roll = rng.gen_range(1..=20) as f64;
+            let roll = self.roll_d20_for(&target_id) as f64;
             let save_bonus = self.get_save_bonus(&target_id, context);
             let total_save = roll + save_bonus;
 
@@ -357,8 +476,7 @@ impl ActionResolver {
             // Perform saving throw for debuffs (bane)
             let mut should_apply = true;
             if template_name == "bane" {
-                let mut rng = rng::get_rng();
-                let roll = rng.gen_range(1..=20) as f64;
+                let roll = self.roll_d20_for(&target_id) as f64;
                 let save_bonus = self.get_save_bonus(&target_id, context);
                 let save_dc = template_action.template_options.save_dc.unwrap_or(13.0);
                 
@@ -451,7 +569,7 @@ impl ActionResolver {
                                         let formula =
                                             crate::model::DiceFormula::Expr(amount.clone());
                                         // Basic eval for now, assume no variable parts specific to reactor yet (except fixed values)
-                                        let dmg_value = dice::evaluate(&formula, 1);
+                                        let dmg_value = self.evaluate_for(reactor_id, &formula, 1);
 
                                         // Apply damage to the TRIGGERING ACTOR (Retaliation)
                                         let dmg_events = context.apply_damage(
@@ -463,6 +581,22 @@ impl ActionResolver {
                                         events.extend(dmg_events);
                                     }
                                 }
+                                crate::enums::TriggerEffect::Script { source } => {
+                                    let key = crate::rune_scripting::trigger_cache_key(trigger);
+                                    for mutation in crate::rune_scripting::run_effect_script(key, source, context) {
+                                        match mutation {
+                                            crate::rune_scripting::ScriptMutation::DealDamage { target_id, amount, damage_type } => {
+                                                events.extend(context.apply_damage(&target_id, amount, &damage_type, reactor_id));
+                                            }
+                                            crate::rune_scripting::ScriptMutation::Heal { target_id, amount } => {
+                                                if let Some(c) = context.combatants.get_mut(&target_id) {
+                                                    let max_hp = c.base_combatant.creature.hp as f64;
+                                                    c.current_hp = (c.current_hp + amount).min(max_hp);
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
                                 // Implement other effects as needed
                                 _ => {}
                             }
@@ -682,6 +816,16 @@ impl ActionResolver {
                     let dpr_b = b.cached_stats.as_ref().map(|s| s.total_dpr).unwrap_or(0.0);
                     dpr_b.partial_cmp(&dpr_a).unwrap_or(Ordering::Equal)
                 }
+                crate::enums::EnemyTarget::FocusFire => {
+                    // Approximate "actual damage" with this attack's average damage scaled by
+                    // each candidate's damage-type modifier (resistance/immunity/vulnerability);
+                    // hit chance is not part of this ranking.
+                    let (mult_a, _) = a.base_combatant.creature.damage_type_modifier(attack.damage_type);
+                    let (mult_b, _) = b.base_combatant.creature.damage_type_modifier(attack.damage_type);
+                    let dmg_a = dice::average(&attack.dpr) * mult_a;
+                    let dmg_b = dice::average(&attack.dpr) * mult_b;
+                    dmg_b.partial_cmp(&dmg_a).unwrap_or(Ordering::Equal)
+                }
             };
             if primary != Ordering::Equal {
                 return primary;
@@ -768,9 +912,7 @@ impl ActionResolver {
     }
 
     /// Roll attack value
-    fn roll_attack(&self, attack: &AtkAction, context: &TurnContext, actor_id: &str, target_id: &str) -> AttackRollResult {
-        let mut rng = rng::get_rng();
-        
+    fn roll_attack(&self, attack: &AtkAction, context: &TurnContext, actor_id: &str, target_id: &str, mode: AttackMode) -> AttackRollResult {
         // 1. Determine Advantage/Disadvantage
         let attacker_has_adv = context.has_condition(actor_id, crate::enums::CreatureCondition::AttacksWithAdvantage)
             || context.has_condition(actor_id, crate::enums::CreatureCondition::AttacksAndIsAttackedWithAdvantage);
@@ -783,29 +925,21 @@ impl ActionResolver {
         let final_adv = (attacker_has_adv || target_grants_adv) && !(attacker_has_dis || target_grants_dis);
         let final_dis = (attacker_has_dis || target_grants_dis) && !(attacker_has_adv || target_grants_adv);
 
-        // 2. Perform Roll
-        let roll1 = rng.gen_range(1..=20);
-        let natural_roll: u32;
-        
-        if final_adv {
-            let roll2 = rng.gen_range(1..=20);
-            natural_roll = roll1.max(roll2);
-        } else if final_dis {
-            let roll2 = rng.gen_range(1..=20);
-            natural_roll = roll1.min(roll2);
-        } else {
-            natural_roll = roll1;
-        }
+        // 2. Perform Roll (drawn from the attacker's own sub-stream)
+        let natural_roll = self.roll_d20_with_advantage(actor_id, final_adv, final_dis);
 
         let (modifier_total, roll_detail) = if context.log_enabled {
-            let detail = dice::evaluate_detailed(&attack.to_hit, 1);
+            let detail = self.evaluate_detailed_for(actor_id, &attack.to_hit, 1);
             (detail.total, Some(detail))
         } else {
-            (dice::evaluate(&attack.to_hit, 1), None)
+            (self.evaluate_for(actor_id, &attack.to_hit, 1), None)
         };
 
         let mut total = natural_roll as f64 + modifier_total;
-        
+        if mode == AttackMode::Power {
+            total += POWER_ATTACK_TO_HIT_PENALTY;
+        }
+
         // Check for accuracy-altering buffs in active effects
         let mut final_roll_detail = roll_detail;
         
@@ -819,14 +953,14 @@ impl ActionResolver {
         for (_, buff) in attacker_buffs {
             if let Some(to_hit_formula) = &buff.to_hit {
                 if context.log_enabled {
-                    let buff_roll = dice::evaluate_detailed(to_hit_formula, 1);
+                    let buff_roll = self.evaluate_detailed_for(actor_id, to_hit_formula, 1);
                     total += buff_roll.total;
                     if let Some(detail) = &mut final_roll_detail {
                         detail.modifiers.push((buff.display_name.clone().unwrap_or_else(|| "Buff".to_string()), buff_roll.total));
                         detail.total += buff_roll.total;
                     }
                 } else {
-                    total += dice::evaluate(to_hit_formula, 1);
+                    total += self.evaluate_for(actor_id, to_hit_formula, 1);
                 }
             }
         }
@@ -888,14 +1022,22 @@ impl ActionResolver {
     }
 
     /// Calculate damage from attack
-    fn calculate_damage(&self, attack: &AtkAction, is_critical: bool, context: &TurnContext, actor_id: &str) -> (f64, Option<RollResult>) {
+    fn calculate_damage(&self, attack: &AtkAction, is_critical: bool, context: &TurnContext, actor_id: &str, mode: AttackMode) -> (f64, Option<RollResult>) {
         let (mut damage, mut damage_roll) = if context.log_enabled {
-            let detail = dice::evaluate_detailed(&attack.dpr, if is_critical { 2 } else { 1 });
+            let detail = self.evaluate_detailed_for(actor_id, &attack.dpr, if is_critical { 2 } else { 1 });
             (detail.total, Some(detail))
         } else {
-            (dice::evaluate(&attack.dpr, if is_critical { 2 } else { 1 }), None)
+            (self.evaluate_for(actor_id, &attack.dpr, if is_critical { 2 } else { 1 }), None)
         };
 
+        if mode == AttackMode::Power {
+            damage += POWER_ATTACK_DAMAGE_BONUS;
+            if let Some(detail) = &mut damage_roll {
+                detail.modifiers.push(("Power Attack".to_string(), POWER_ATTACK_DAMAGE_BONUS));
+                detail.total += POWER_ATTACK_DAMAGE_BONUS;
+            }
+        }
+
         // Add damage bonuses from active buffs
         let mut attacker_buffs: Vec<_> = context.active_effects.values()
             .filter(|e| e.target_id == actor_id)
@@ -906,14 +1048,14 @@ impl ActionResolver {
         for (_, buff) in attacker_buffs {
             if let Some(damage_formula) = &buff.damage {
                 if context.log_enabled {
-                    let buff_dmg_roll = dice::evaluate_detailed(damage_formula, 1);
+                    let buff_dmg_roll = self.evaluate_detailed_for(actor_id, damage_formula, 1);
                     damage += buff_dmg_roll.total;
                     if let Some(detail) = &mut damage_roll {
                         detail.modifiers.push((buff.display_name.clone().unwrap_or_else(|| "Damage Buff".to_string()), buff_dmg_roll.total));
                         detail.total += buff_dmg_roll.total;
                     }
                 } else {
-                    damage += dice::evaluate(damage_formula, 1);
+                    damage += self.evaluate_for(actor_id, damage_formula, 1);
                 }
             }
         }
@@ -1041,10 +1183,10 @@ mod tests {
     #[test]
     fn test_action_resolver_creation() {
         let resolver = ActionResolver::new();
-        assert!(resolver.rng_seed.is_none());
+        assert!(resolver.root_seed().is_none());
 
         let resolver_with_seed = ActionResolver::with_seed(42);
-        assert_eq!(resolver_with_seed.rng_seed, Some(42));
+        assert_eq!(resolver_with_seed.root_seed(), Some(42));
     }
 
     #[test]
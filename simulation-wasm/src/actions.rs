@@ -110,6 +110,32 @@ pub fn get_actions(c: &Combattant, allies: &[Combattant], enemies: &[Combattant]
     result
 }
 
+/// Same eligibility filtering as `get_actions`, but borrows from the combatant's own action
+/// list instead of cloning it. `CombatStrategy` implementations (see `crate::strategy`) use
+/// this shape since their `choose_actions` returns references tied to the `allies` slice.
+pub fn get_actions_ref<'a>(c: &'a Combattant, allies: &[Combattant], enemies: &[Combattant]) -> Vec<&'a Action> {
+    let mut result = Vec::new();
+    let mut used_slots = HashSet::new();
+
+    for action in &c.creature.actions {
+        if let Some(slot) = action.base().action_slot {
+            if used_slots.contains(&slot) {
+                continue;
+            }
+            used_slots.insert(slot);
+        }
+        if !is_usable(c, action) {
+            continue;
+        }
+        if !check_action_condition(action, c, allies, enemies) {
+            continue;
+        }
+        result.push(action);
+    }
+
+    result
+}
+
 pub fn is_usable(c: &Combattant, action: &Action) -> bool {
     #[cfg(debug_assertions)]
     eprintln!("        Checking usability for {}: {}. Remaining uses: {:?}", c.creature.name, action.base().name, c.final_state.resources.current.get(&action.base().id));
@@ -1,7 +1,7 @@
-pub mod dto;
+// `dto` and `wasm` are a stale, never-finished rework of the active wasm_api.rs entry points
+// (e.g. `wasm::ChunkedSimulationRunner` duplicates the real one and calls `sorting`/`summarize_result`
+// signatures that don't exist in this tree) - leave them undeclared so they don't shadow or
+// conflict with what actually ships. `runner` holds the real, seed-taking simulation entry points.
 pub mod runner;
-pub mod wasm;
 
-pub use dto::*;
 pub use runner::*;
-pub use wasm::*;
@@ -3,39 +3,87 @@ use crate::execution::ActionExecutionEngine;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-/// Phase 1: Survey pass - runs all iterations with lightweight simulation (no event collection)
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+
+/// Phase 1: Survey pass - runs all iterations with lightweight simulation (no event collection).
+///
+/// Iterations are mapped from a precomputed seed vector rather than the raw index range, so
+/// the seed each iteration uses is pinned down before the parallel map starts rather than
+/// being recomputed per-task. On native targets the seeds run across rayon's thread pool;
+/// each iteration seeds and clears its own thread-local RNG entirely inside
+/// `run_single_lightweight_simulation` (or `..._with_scratch`), so no two iterations ever
+/// observe each other's RNG state no matter which worker thread rayon reuses between
+/// tasks - the seed/clear bracketing, not thread identity, is what makes the result set
+/// identical regardless of thread count, matching the invariant
+/// `test_lightweight_simulation_determinism` and `test_two_pass_consistency` check. Same
+/// approach `simulation::run_monte_carlo` already uses. `par_iter`/`collect` preserve the
+/// input seed vector's order, so results stay ordered by seed. WASM has no thread pool
+/// available, so it falls back to the sequential loop. The per-iteration cache lookup is
+/// thread-local, so parallel iterations each get their own (empty) cache rather than sharing
+/// hits across threads.
 pub fn run_survey_pass(
     players: Vec<Creature>,
     timeline: Vec<crate::model::TimelineStep>,
     iterations: usize,
     base_seed: Option<u64>,
 ) -> Vec<crate::model::LightweightRun> {
-    let mut all_runs = Vec::with_capacity(iterations);
     let scenario_hash = crate::cache::get_scenario_hash(&players, &timeline);
-
-    for i in 0..iterations {
-        let seed = base_seed.unwrap_or(i as u64).wrapping_add(i as u64);
-
-        if let Some(cached_run) = crate::cache::get_cached_run(scenario_hash, seed) {
-            all_runs.push(cached_run);
-            continue;
-        }
-
-        let lightweight_run = run_single_lightweight_simulation(&players, &timeline, seed);
-        crate::cache::insert_cached_run(scenario_hash, seed, lightweight_run.clone());
-        all_runs.push(lightweight_run);
+    let seeds: Vec<u64> = (0..iterations)
+        .map(|i| base_seed.unwrap_or(i as u64).wrapping_add(i as u64))
+        .collect();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        // Each rayon worker thread would need its own `IterationScratch` to reuse buffers
+        // safely, so the parallel path keeps allocating fresh per iteration for now and only
+        // the sequential WASM path (which is what actually has no thread pool to spread the
+        // allocator churn across) gets the scratch-buffer treatment below.
+        seeds
+            .into_par_iter()
+            .map(|seed| {
+                if let Some(cached_run) = crate::cache::get_cached_run(scenario_hash, seed) {
+                    return cached_run;
+                }
+                let lightweight_run = run_single_lightweight_simulation(&players, &timeline, seed);
+                crate::cache::insert_cached_run(scenario_hash, seed, lightweight_run.clone());
+                lightweight_run
+            })
+            .collect()
     }
 
-    all_runs
+    #[cfg(target_arch = "wasm32")]
+    {
+        let mut scratch = crate::scratch::IterationScratch::new();
+        seeds
+            .into_iter()
+            .map(|seed| {
+                if let Some(cached_run) = crate::cache::get_cached_run(scenario_hash, seed) {
+                    return cached_run;
+                }
+                let lightweight_run =
+                    run_single_lightweight_simulation_with_scratch(&players, &timeline, seed, &mut scratch);
+                crate::cache::insert_cached_run(scenario_hash, seed, lightweight_run.clone());
+                lightweight_run
+            })
+            .collect()
+    }
 }
 
-/// Run a single event-driven simulation with full event collection
+/// Run a single event-driven simulation with full event collection.
+///
+/// `seed` is threaded in explicitly rather than read from the thread-local RNG via
+/// `rng::get_current_seed` (which callers previously had to pre-seed with `rng::seed_rng`
+/// before invoking this function, and remember to clear afterwards). Taking the seed by value
+/// means this function is self-contained - safe to call from multiple rayon iterations at once,
+/// each with its own seed, the same way `run_single_lightweight_simulation` already does.
 pub fn run_single_event_driven_simulation(
     players: &[Creature],
     timeline: &[crate::model::TimelineStep],
+    seed: u64,
     _log_enabled: bool,
 ) -> (SimulationResult, Vec<crate::events::Event>) {
-    let seed = crate::rng::get_current_seed();
+    crate::rng::seed_rng(seed);
     let mut all_events = Vec::new();
     let mut players_with_state = initialize_players(players);
 
@@ -52,7 +100,11 @@ pub fn run_single_event_driven_simulation(
                 let mut all_combatants = players_with_state.clone();
                 all_combatants.extend(enemies);
 
-                let mut engine = ActionExecutionEngine::new(all_combatants.clone(), true);
+                // Each encounter gets its own derived seed (rather than reusing `seed` as-is)
+                // so combatants that reappear across encounters - most players do - don't replay
+                // an identical roll sequence every time; see `ActionResolver::with_seed`.
+                let encounter_seed = seed.wrapping_add(step_idx as u64);
+                let mut engine = ActionExecutionEngine::new_with_seed(all_combatants.clone(), true, encounter_seed);
                 let encounter_result = engine.execute_encounter();
 
                 all_events.extend(encounter_result.event_history.clone());
@@ -94,6 +146,8 @@ pub fn run_single_event_driven_simulation(
     let score = crate::aggregation::calculate_efficiency_score(&result, &all_events);
     result.score = Some(score);
 
+    crate::rng::clear_rng();
+
     (result, all_events)
 }
 
@@ -102,6 +156,21 @@ pub fn run_single_lightweight_simulation(
     players: &[Creature],
     timeline: &[crate::model::TimelineStep],
     seed: u64,
+) -> crate::model::LightweightRun {
+    let mut scratch = crate::scratch::IterationScratch::new();
+    run_single_lightweight_simulation_with_scratch(players, timeline, seed, &mut scratch)
+}
+
+/// Same as `run_single_lightweight_simulation`, but builds each encounter's combatant roster
+/// in `scratch`'s reusable buffer - see `scratch::IterationScratch`. The engine still receives a
+/// clone of that roster, so each encounter allocates one `Vec` for it.
+/// Intended for callers (e.g. `run_survey_pass`'s WASM sequential path) that run many
+/// iterations back-to-back and can carry one `IterationScratch` across all of them.
+pub fn run_single_lightweight_simulation_with_scratch(
+    players: &[Creature],
+    timeline: &[crate::model::TimelineStep],
+    seed: u64,
+    scratch: &mut crate::scratch::IterationScratch,
 ) -> crate::model::LightweightRun {
     crate::rng::seed_rng(seed);
 
@@ -115,10 +184,16 @@ pub fn run_single_lightweight_simulation(
         match step {
             crate::model::TimelineStep::Combat(encounter) => {
                 let enemies = initialize_enemies(step_idx, &encounter.monsters);
-                let mut all_combatants = players_with_state.clone();
+                let all_combatants = scratch.combatants_buffer();
+                all_combatants.extend(players_with_state.iter().cloned());
                 all_combatants.extend(enemies);
 
-                let mut engine = ActionExecutionEngine::new(all_combatants.clone(), false);
+                // See `run_single_event_driven_simulation`: derive a per-encounter seed so
+                // recurring player combatant IDs don't replay the same rolls every encounter.
+                let encounter_seed = seed.wrapping_add(step_idx as u64);
+                let mut engine =
+                    ActionExecutionEngine::new_with_seed(all_combatants.clone(), false, encounter_seed);
+                scratch.record_high_water();
                 let encounter_result = engine.execute_encounter();
 
                 let score = crate::safe_aggregation::calculate_lightweight_score(
@@ -0,0 +1,264 @@
+//! Batch benchmark runner: survey a set of named scenarios across a shared seed range and emit
+//! a win-rate / death-rate / percentile comparison table that is byte-stable for a given seed
+//! range, so balance changes can be diffed over time as a regression artifact.
+//!
+//! Builds on the same Phase 1 survey pass `two_pass::run_simulation_with_rolling_stats` uses
+//! (`run_survey_pass` + `seed_selection::select_interesting_seeds_with_tiers`), but stops short
+//! of that function's Phase 3 deep-dive re-simulation: a benchmark table only needs the
+//! `LightweightRun` numbers (scores, deaths, seeds), and re-running ~170 seeds with full event
+//! collection per scenario would be pure overhead here. The percentile math below mirrors
+//! `two_pass`'s `sorted_scores[len/4]`/`len/2`/`len*3/4` convention exactly, so the numbers match
+//! what `run_simulation_with_rolling_stats` would have reported for the same seed range.
+
+use crate::model::{Creature, ScorePercentiles, SelectedSeed, TimelineStep};
+use crate::seed_selection::select_interesting_seeds_with_tiers;
+use serde::Serialize;
+use std::ops::Range;
+
+/// One named party + timeline pair entering the benchmark.
+#[derive(Debug, Clone)]
+pub struct BenchmarkScenario {
+    pub name: String,
+    pub players: Vec<Creature>,
+    pub timeline: Vec<TimelineStep>,
+}
+
+/// Win rate, death rate, and score percentiles over one slice of a scenario's runs (the whole
+/// run, or a single encounter's cumulative score when split by encounter).
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreSummary {
+    /// Whole run: fraction of runs that ended with at least one survivor. Per-encounter rows:
+    /// fraction of runs with no party death up to and including that encounter.
+    pub win_rate: f64,
+    /// Fraction of runs in which a party member (team 0) died, whether or not the party was
+    /// wiped. Per-encounter rows reuse this whole-run flag.
+    pub death_rate: f64,
+    pub score_percentiles: ScorePercentiles,
+}
+
+/// One timeline position's score summary, for `split_by_encounter` tables.
+#[derive(Debug, Clone, Serialize)]
+pub struct EncounterBreakdown {
+    pub encounter_index: usize,
+    pub summary: ScoreSummary,
+}
+
+/// A single extreme run worth replaying, e.g. the worst seed in a scenario.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeedHighlight {
+    pub seed: u64,
+    pub final_score: f64,
+}
+
+/// One scenario's full benchmark result.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkRow {
+    pub scenario: String,
+    pub iterations: usize,
+    pub overall: ScoreSummary,
+    /// Empty unless `run_benchmark` was called with `split_by_encounter: true`.
+    pub per_encounter: Vec<EncounterBreakdown>,
+    pub best_seed: SeedHighlight,
+    pub worst_seed: SeedHighlight,
+    /// The full interesting-seed set from `select_interesting_seeds_with_tiers`, so a caller can
+    /// replay any of them (not just the global best/worst) with the seed alone.
+    pub interesting_seeds: Vec<SelectedSeed>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkTable {
+    pub rows: Vec<BenchmarkRow>,
+}
+
+impl BenchmarkTable {
+    /// Serialize the table to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render one row per scenario, `win_rate,death_rate,p25,median,p75` - the headline columns
+    /// a balance-regression diff cares about. Per-encounter breakdowns aren't flattened into
+    /// CSV; read `per_encounter` from the struct (or the Markdown rendering) for those.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("scenario,iterations,win_rate,death_rate,p25,median,p75\n");
+        for row in &self.rows {
+            out.push_str(&format!(
+                "{},{},{:.4},{:.4},{:.4},{:.4},{:.4}\n",
+                row.scenario,
+                row.iterations,
+                row.overall.win_rate,
+                row.overall.death_rate,
+                row.overall.score_percentiles.p25,
+                row.overall.score_percentiles.median,
+                row.overall.score_percentiles.p75,
+            ));
+        }
+        out
+    }
+
+    /// Render a Markdown table with one row per scenario, followed by a per-encounter breakdown
+    /// table for any scenario that was surveyed with `split_by_encounter: true`.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from(
+            "| Scenario | Iterations | Win Rate | Death Rate | P25 | Median | P75 | Best Seed | Worst Seed |\n\
+             |---|---|---|---|---|---|---|---|---|\n",
+        );
+        for row in &self.rows {
+            out.push_str(&format!(
+                "| {} | {} | {:.2}% | {:.2}% | {:.1} | {:.1} | {:.1} | {} | {} |\n",
+                row.scenario,
+                row.iterations,
+                row.overall.win_rate * 100.0,
+                row.overall.death_rate * 100.0,
+                row.overall.score_percentiles.p25,
+                row.overall.score_percentiles.median,
+                row.overall.score_percentiles.p75,
+                row.best_seed.seed,
+                row.worst_seed.seed,
+            ));
+        }
+
+        for row in &self.rows {
+            if row.per_encounter.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n**{}** by encounter\n\n", row.scenario));
+            out.push_str("| Encounter | Win Rate | Death Rate | P25 | Median | P75 |\n|---|---|---|---|---|---|\n");
+            for breakdown in &row.per_encounter {
+                out.push_str(&format!(
+                    "| {} | {:.2}% | {:.2}% | {:.1} | {:.1} | {:.1} |\n",
+                    breakdown.encounter_index,
+                    breakdown.summary.win_rate * 100.0,
+                    breakdown.summary.death_rate * 100.0,
+                    breakdown.summary.score_percentiles.p25,
+                    breakdown.summary.score_percentiles.median,
+                    breakdown.summary.score_percentiles.p75,
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Percentiles/win-death rate over a set of (score, has_death, is_win) triples, using the same
+/// `sorted[len/4]`/`len/2`/`len*3/4` index convention `two_pass`'s rolling-stats summary uses.
+fn summarize(mut samples: Vec<(f64, bool, bool)>) -> ScoreSummary {
+    let total = samples.len();
+    samples.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let scores: Vec<f64> = samples.iter().map(|s| s.0).collect();
+    let min = *scores.first().unwrap_or(&0.0);
+    let max = *scores.last().unwrap_or(&0.0);
+    let sum: f64 = scores.iter().sum();
+    let mean = if total > 0 { sum / total as f64 } else { 0.0 };
+    let variance = if total > 0 {
+        scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / total as f64
+    } else {
+        0.0
+    };
+    let median = if !scores.is_empty() { scores[scores.len() / 2] } else { 0.0 };
+    let p25 = if !scores.is_empty() { scores[scores.len() / 4] } else { 0.0 };
+    let p75 = if !scores.is_empty() { scores[scores.len() * 3 / 4] } else { 0.0 };
+
+    let deaths = samples.iter().filter(|s| s.1).count();
+    let wins = samples.iter().filter(|s| s.2).count();
+
+    ScoreSummary {
+        win_rate: if total > 0 { wins as f64 / total as f64 } else { 0.0 },
+        death_rate: if total > 0 { deaths as f64 / total as f64 } else { 0.0 },
+        score_percentiles: ScorePercentiles {
+            min,
+            max,
+            median,
+            p25,
+            p75,
+            mean,
+            std_dev: variance.sqrt().max(0.0),
+        },
+    }
+}
+
+/// Surveys every scenario over `seed_range` (iterations = `seed_range.end - seed_range.start`,
+/// base seed = `seed_range.start` - `run_survey_pass` derives each iteration's seed as
+/// `base_seed + i`, so this call covers exactly that range) and builds one `BenchmarkRow` per
+/// scenario. `split_by_encounter` additionally reduces each timeline position's cumulative
+/// `encounter_scores` into its own `EncounterBreakdown`, so a multi-encounter timeline shows
+/// where a party tends to fail rather than just its final outcome.
+pub fn run_benchmark(
+    scenarios: &[BenchmarkScenario],
+    seed_range: Range<u64>,
+    split_by_encounter: bool,
+) -> BenchmarkTable {
+    let iterations = seed_range.end.saturating_sub(seed_range.start) as usize;
+    let base_seed = seed_range.start;
+
+    let rows = scenarios
+        .iter()
+        .map(|scenario| {
+            let lightweight_runs = crate::run_survey_pass(
+                scenario.players.clone(),
+                scenario.timeline.clone(),
+                iterations,
+                Some(base_seed),
+            );
+
+            let overall = summarize(
+                lightweight_runs
+                    .iter()
+                    .map(|r| (r.final_score, r.has_death, r.total_survivors > 0))
+                    .collect(),
+            );
+
+            let per_encounter = if split_by_encounter {
+                let num_encounters = lightweight_runs
+                    .first()
+                    .map(|r| r.encounter_scores.len())
+                    .unwrap_or(0);
+                (0..num_encounters)
+                    .map(|i| EncounterBreakdown {
+                        encounter_index: i,
+                        summary: summarize(
+                            lightweight_runs
+                                .iter()
+                                .filter_map(|r| {
+                                    r.encounter_scores.get(i).map(|&score| {
+                                        let failed_by_here =
+                                            r.first_death_encounter.is_some_and(|d| d <= i);
+                                        (score, r.has_death, !failed_by_here)
+                                    })
+                                })
+                                .collect(),
+                        ),
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            let best = lightweight_runs
+                .iter()
+                .max_by(|a, b| a.final_score.partial_cmp(&b.final_score).unwrap())
+                .map(|r| SeedHighlight { seed: r.seed, final_score: r.final_score })
+                .unwrap_or(SeedHighlight { seed: base_seed, final_score: 0.0 });
+            let worst = lightweight_runs
+                .iter()
+                .min_by(|a, b| a.final_score.partial_cmp(&b.final_score).unwrap())
+                .map(|r| SeedHighlight { seed: r.seed, final_score: r.final_score })
+                .unwrap_or(SeedHighlight { seed: base_seed, final_score: 0.0 });
+
+            let interesting_seeds = select_interesting_seeds_with_tiers(&lightweight_runs);
+
+            BenchmarkRow {
+                scenario: scenario.name.clone(),
+                iterations,
+                overall,
+                per_encounter,
+                best_seed: best,
+                worst_seed: worst,
+                interesting_seeds,
+            }
+        })
+        .collect();
+
+    BenchmarkTable { rows }
+}
@@ -34,6 +34,12 @@ enum Commands {
         /// Run index (if running from a batch, 0-indexed)
         #[arg(short, long)]
         run_index: Option<usize>,
+        /// Force ANSI color on, even when stdout isn't a TTY
+        #[arg(long, conflicts_with = "no_color")]
+        color: bool,
+        /// Disable ANSI color, even when stdout is a TTY
+        #[arg(long)]
+        no_color: bool,
     },
     /// Find the simulation run closest to the median decile
     FindMedian {
@@ -111,8 +117,11 @@ fn main() {
             scenario,
             format,
             run_index,
+            color,
+            no_color,
         } => {
-            run_log(&scenario, &format, run_index);
+            let use_color = color || simulation_wasm::colorize::color_enabled(no_color);
+            run_log(&scenario, &format, run_index, use_color);
         }
         Commands::FindMedian { scenario } => {
             run_find_median(&scenario);
@@ -223,7 +232,7 @@ fn run_aggregate(scenario_path: &PathBuf) {
 
 // --- Log Subcommand ---
 
-fn run_log(scenario_path: &PathBuf, format: &str, run_index: Option<usize>) {
+fn run_log(scenario_path: &PathBuf, format: &str, run_index: Option<usize>, color: bool) {
     let (players, timeline, _) = load_scenario(scenario_path);
 
     // If run_index is provided, run that many + 1 and pick the specific one
@@ -269,18 +278,19 @@ fn run_log(scenario_path: &PathBuf, format: &str, run_index: Option<usize>) {
                 .iter()
                 .filter_map(|e| e.format_for_log(&combatant_names))
                 .collect();
-            print_markdown_log(result, &formatted_events);
+            print_markdown_log(result, &formatted_events, color);
         }
     }
 }
 
-fn print_markdown_log(result: &SimulationResult, events: &[String]) {
+fn print_markdown_log(result: &SimulationResult, events: &[String], color: bool) {
     println!("# Combat Log\n");
 
     for (enc_idx, encounter) in result.encounters.iter().enumerate() {
         println!("## Encounter {}\n", enc_idx + 1);
         for (round_idx, round) in encounter.rounds.iter().enumerate() {
-            println!("### Round {}\n", round_idx + 1);
+            let header = format!("### Round {}\n", round_idx + 1);
+            println!("{}", simulation_wasm::colorize::colorize_log(&[header], color).remove(0));
 
             // Show all combatants sorted by initiative
             let mut all: Vec<_> = round.team1.iter().chain(round.team2.iter()).collect();
@@ -327,8 +337,8 @@ fn print_markdown_log(result: &SimulationResult, events: &[String]) {
     // Print raw events if available
     if !events.is_empty() {
         println!("## Raw Event Log\n");
-        for event in events {
-            println!("- {}", event);
+        for line in simulation_wasm::colorize::colorize_log(events, color) {
+            println!("- {}", line);
         }
     }
 }
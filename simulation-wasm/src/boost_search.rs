@@ -0,0 +1,139 @@
+// Binary-search analysis answering "how much stronger must the players be to win
+// reliably?" alongside `simulation::run_monte_carlo`. Reuses that same parallel seeded
+// engine for every probe so the search is reproducible under a fixed seed.
+use crate::dice;
+use crate::model::*;
+use crate::simulation;
+
+/// Upper bound on the exponential growth phase, so a target win rate that's never
+/// reachable (e.g. > 1.0, or an encounter that always wipes regardless of boost) can't
+/// spin the search forever.
+const MAX_BOOST: i32 = 1024;
+
+/// How a scalar "boost" is distributed over a player's `Creature`. Parameterized rather
+/// than hard-coded so callers can compare a narrow buff against a broad one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoostKind {
+    /// +1 to every attack action's to-hit bonus and average damage per point of boost.
+    FlatAttackAndDamage,
+    /// Scales HP and average damage by `(1.0 + 0.1 * boost)`, approximating "go up a level".
+    LevelMultiplier,
+}
+
+/// One probed (boost, win rate) pair from the search, so callers can chart the
+/// difficulty cliff rather than just the minimal answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoostProbe {
+    pub boost: i32,
+    pub win_rate: f64,
+}
+
+/// Outcome of `find_minimum_boost_for_win_rate`.
+#[derive(Debug, Clone)]
+pub struct BoostSearchResult {
+    /// Smallest boost, in `[0, MAX_BOOST]`, whose measured win rate met the target. `None`
+    /// if even `MAX_BOOST` fell short.
+    pub minimal_boost: Option<i32>,
+    /// Every (boost, win_rate) pair probed along the way, in probe order.
+    pub curve: Vec<BoostProbe>,
+}
+
+/// Apply `boost` points of `kind` to a copy of `creature`, leaving the original untouched.
+fn boosted_creature(creature: &Creature, kind: BoostKind, boost: i32) -> Creature {
+    let mut creature = creature.clone();
+    if boost <= 0 {
+        return creature;
+    }
+    let boost = boost as f64;
+    match kind {
+        BoostKind::FlatAttackAndDamage => {
+            for action in &mut creature.actions {
+                if let Action::Atk(atk) = action {
+                    atk.to_hit = DiceFormula::Value(dice::average(&atk.to_hit) + boost);
+                    atk.dpr = DiceFormula::Value(dice::average(&atk.dpr) + boost);
+                }
+            }
+        }
+        BoostKind::LevelMultiplier => {
+            let scale = 1.0 + 0.1 * boost;
+            creature.hp = ((creature.hp as f64) * scale).round().max(1.0) as u32;
+            for action in &mut creature.actions {
+                if let Action::Atk(atk) = action {
+                    atk.dpr = DiceFormula::Value(dice::average(&atk.dpr) * scale);
+                }
+            }
+        }
+    }
+    creature
+}
+
+/// Apply `boost` to every player, run a Monte Carlo batch, and measure the resulting win
+/// rate. Reuses `simulation::run_monte_carlo`, so each probe gets the same parallel/seeded
+/// engine as a normal analysis run.
+fn probe_win_rate(
+    players: &[Creature],
+    encounters: &[Encounter],
+    iterations: usize,
+    seed: u64,
+    kind: BoostKind,
+    boost: i32,
+) -> f64 {
+    let boosted_players: Vec<Creature> = players
+        .iter()
+        .map(|player| boosted_creature(player, kind, boost))
+        .collect();
+    let results = simulation::run_monte_carlo(&boosted_players, encounters, iterations, seed);
+    if results.is_empty() {
+        return 0.0;
+    }
+    let wins = results.iter().filter(|result| simulation::run_is_win(result)).count();
+    wins as f64 / results.len() as f64
+}
+
+/// Binary-search the smallest integer boost (of `kind`) for which the measured win rate
+/// across an `iterations`-run Monte Carlo batch meets `target_win_rate`. Win rate is
+/// monotonic in boost in expectation, so the search grows an upper bound exponentially
+/// until the target is met, then bisects down to the minimal passing value. Every probed
+/// (boost, win_rate) pair is returned alongside the answer.
+pub fn find_minimum_boost_for_win_rate(
+    players: &[Creature],
+    encounters: &[Encounter],
+    iterations: usize,
+    target_win_rate: f64,
+    kind: BoostKind,
+    seed: u64,
+) -> BoostSearchResult {
+    let mut curve = Vec::new();
+    let mut probe = |boost: i32, curve: &mut Vec<BoostProbe>| -> f64 {
+        let win_rate = probe_win_rate(players, encounters, iterations, seed, kind, boost);
+        curve.push(BoostProbe { boost, win_rate });
+        win_rate
+    };
+
+    if probe(0, &mut curve) >= target_win_rate {
+        return BoostSearchResult { minimal_boost: Some(0), curve };
+    }
+
+    let mut low = 0;
+    let mut high = 1;
+    let mut high_rate = probe(high, &mut curve);
+    while high_rate < target_win_rate {
+        if high >= MAX_BOOST {
+            return BoostSearchResult { minimal_boost: None, curve };
+        }
+        low = high;
+        high = (high * 2).min(MAX_BOOST);
+        high_rate = probe(high, &mut curve);
+    }
+
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if probe(mid, &mut curve) >= target_win_rate {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    BoostSearchResult { minimal_boost: Some(high), curve }
+}
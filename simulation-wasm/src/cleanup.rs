@@ -1,4 +1,9 @@
+use crate::enums::{TriggerCondition, TriggerEffect};
 use crate::model::*;
+use crate::resolution::{update_stats, update_stats_buff};
+use crate::{dice, rng};
+use rand::Rng;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 pub fn remove_dead_buffs(targets: &mut [Combattant], dead_source_ids: &HashSet<String>) {
@@ -36,3 +41,124 @@ pub fn remove_dead_buffs(targets: &mut [Combattant], dead_source_ids: &HashSet<S
         }
     }
 }
+
+/// Runs `source_id`'s `TriggerCondition::OnDeath` buff triggers - e.g. a death-burst aura that
+/// forces a save on everyone else in the encounter. Must run before `remove_dead_buffs`/
+/// `remove_all_buffs_from_source` for the same `source_id`, since it reads the dying creature's
+/// own `final_state.buffs`.
+///
+/// Only `Buff::triggers` (`Vec<EffectTrigger>`) are considered - `Creature::triggers` is a
+/// separate, older `Vec<ActionTrigger>` system that fires full `Action`s rather than
+/// `TriggerEffect`s, so `TriggerEffect::AreaEffect` (the only effect this function currently
+/// knows how to execute) can't appear there.
+///
+/// There is no positional/radius model on `Combattant` (only `context::CombattantState` has a
+/// placeholder `position`), so "every combatant in radius" is approximated as "every
+/// other living combatant in the encounter", further narrowed by `TriggerEffect::AreaEffect`'s
+/// `reaction_filter` if set. `factions` is the `FactionTable` to resolve that filter against;
+/// `None` falls back to the plain two-team model (see `factions::FactionTable::reaction_between`).
+pub fn apply_on_death_triggers(
+    source_id: &str,
+    team1: &mut [Combattant],
+    team2: &mut [Combattant],
+    stats: &mut HashMap<String, EncounterStats>,
+    log: &mut Vec<String>,
+    log_enabled: bool,
+    factions: Option<&crate::factions::FactionTable>,
+) -> Vec<crate::events::Event> {
+    let source_team = team1.iter().chain(team2.iter()).find(|c| c.id == source_id).map(|c| c.team);
+    let Some(source_team) = source_team else {
+        return Vec::new();
+    };
+
+    let dying_buffs: Vec<Buff> = team1
+        .iter()
+        .chain(team2.iter())
+        .find(|c| c.id == source_id)
+        .map(|c| c.final_state.buffs.values().cloned().collect())
+        .unwrap_or_default();
+
+    let area_effects: Vec<(
+        crate::model::DiceFormula,
+        Option<crate::model::DiceFormula>,
+        Buff,
+        Option<crate::factions::Reaction>,
+    )> = dying_buffs
+        .iter()
+        .flat_map(|buff| buff.triggers.iter())
+        .filter(|trigger| trigger.condition == TriggerCondition::OnDeath)
+        .filter_map(|trigger| match &trigger.effect {
+            TriggerEffect::AreaEffect { dc, buff, damage, reaction_filter, .. } => {
+                Some((dc.clone(), damage.clone(), buff.clone(), *reaction_filter))
+            }
+            _ => None,
+        })
+        .collect();
+
+    if area_effects.is_empty() {
+        return Vec::new();
+    }
+
+    #[cfg(debug_assertions)]
+    eprintln!("CLEANUP: {} triggers {} on-death area effect(s)", source_id, area_effects.len());
+
+    if log_enabled {
+        log.push(format!("  * 💥 {}'s death unleashes a burst effect!", source_id));
+    }
+
+    for target in team1.iter_mut().chain(team2.iter_mut()).filter(|c| c.id != source_id) {
+        if target.final_state.current_hp == 0 {
+            continue;
+        }
+
+        for (dc_formula, damage_formula, buff, reaction_filter) in &area_effects {
+            if let Some(wanted_reaction) = reaction_filter {
+                let actual_reaction = match factions {
+                    Some(table) => table.reaction_between(source_id, source_team, &target.id, target.team),
+                    None => if target.team == source_team { crate::factions::Reaction::Friendly } else { crate::factions::Reaction::Hostile },
+                };
+                if actual_reaction != *wanted_reaction {
+                    continue;
+                }
+            }
+
+            let dc = dice::evaluate(dc_formula, 1);
+            let save_bonus = target.creature.save_bonus;
+            let roll = rng::get_rng().gen_range(1..=20) as f64;
+
+            if log_enabled {
+                log.push(format!(
+                    "             -> {} vs {}: DC {:.0} vs Save {:.0} (Rolled {:.0} + {:.0})",
+                    buff.display_name.as_deref().unwrap_or("death burst"),
+                    target.creature.name,
+                    dc,
+                    roll + save_bonus,
+                    roll,
+                    save_bonus
+                ));
+            }
+
+            if roll + save_bonus < dc {
+                if let Some(damage_formula) = damage_formula {
+                    let damage = dice::evaluate(damage_formula, 1);
+                    target.final_state.current_hp =
+                        target.final_state.current_hp.saturating_sub(damage.round().max(0.0) as u32);
+                    update_stats(stats, source_id, &target.id, damage, 0.0);
+                }
+
+                let mut applied_buff = buff.clone();
+                applied_buff.source = Some(source_id.to_string());
+                target.final_state.buffs.insert(format!("{}-on-death", source_id), applied_buff);
+                update_stats_buff(stats, source_id, &target.id, false);
+
+                if log_enabled {
+                    log.push("             Failed! Caught in the burst.".to_string());
+                }
+            } else if log_enabled {
+                log.push("             Saved!".to_string());
+            }
+        }
+    }
+
+    vec![crate::events::Event::CreatureDied { creature_id: source_id.to_string() }]
+}
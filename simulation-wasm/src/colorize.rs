@@ -0,0 +1,160 @@
+// ANSI-colored rendering of the plain-text combat log, for the CLI only. The WASM
+// `web_sys::console` path in `simulation::run_monte_carlo` stays plain text — terminal
+// color codes would just show up as garbage in the browser console.
+use std::io::IsTerminal;
+
+/// A terminal foreground color, limited to the handful this renderer actually uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,     // Damage dealt
+    Green,   // Healing
+    Yellow,  // Crits (combined with bold)
+    Magenta, // Concentration breaks
+    Dim,     // Saves and misses
+}
+
+impl Color {
+    fn sgr_code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Magenta => "35",
+            Color::Dim => "2",
+        }
+    }
+}
+
+/// A restorable terminal style: bold + an optional foreground color. Every span is
+/// rendered as a full reset (`\x1b[0m`) followed by this style's codes, so a span never
+/// inherits stray attributes left over from whatever came before it — including a span
+/// that got cut off mid-line, which matters once output is truncated (e.g. the WASM
+/// console's 100-line cap on the equivalent plain-text path).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    pub bold: bool,
+    pub fg: Option<Color>,
+}
+
+impl Style {
+    pub const PLAIN: Style = Style { bold: false, fg: None };
+
+    fn bold() -> Style {
+        Style { bold: true, fg: None }
+    }
+
+    fn fg(color: Color) -> Style {
+        Style { bold: false, fg: Some(color) }
+    }
+
+    fn bold_fg(color: Color) -> Style {
+        Style { bold: true, fg: Some(color) }
+    }
+
+    fn ansi_sequence(self) -> String {
+        let mut codes = vec!["0"]; // Always reset first so spans never bleed into each other.
+        if self.bold {
+            codes.push("1");
+        }
+        if let Some(color) = self.fg {
+            codes.push(color.sgr_code());
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// Wraps `text` in `span_style`, then restores `base_style` (reset-then-reapply) so
+/// whatever follows in the line picks back up at the line's own style rather than
+/// plain/default.
+fn wrap_span(out: &mut String, base_style: Style, span_style: Style, text: &str) {
+    out.push_str(&span_style.ansi_sequence());
+    out.push_str(text);
+    out.push_str(&base_style.ansi_sequence());
+}
+
+/// Colorize the markers this crate's log lines are known to emit: damage numbers red,
+/// healing green, crits bold yellow, concentration breaks magenta, saves/misses dim.
+/// Anything else passes through in `base_style` (e.g. bold for a round header).
+fn colorize_markers(line: &str, base_style: Style) -> String {
+    const MARKERS: &[(&str, Style)] = &[
+        ("(CRIT!)", Style { bold: true, fg: Some(Color::Yellow) }),
+        ("❌ **MISS**", Style { bold: false, fg: Some(Color::Dim) }),
+        ("Saved!", Style { bold: false, fg: Some(Color::Dim) }),
+        ("Failed!", Style { bold: false, fg: Some(Color::Dim) }),
+        ("Drops concentration", Style { bold: false, fg: Some(Color::Magenta) }),
+        ("concentration on", Style { bold: false, fg: Some(Color::Magenta) }),
+    ];
+
+    let mut out = String::with_capacity(line.len() + 16);
+    out.push_str(&base_style.ansi_sequence());
+
+    // Damage/heal lines color the number that follows the label, not the label itself.
+    if let Some(rest) = line.strip_prefix("  * 🩸 Damage: ") {
+        out.push_str("  * 🩸 Damage: ");
+        wrap_span(&mut out, base_style, Style::fg(Color::Red), rest);
+        return out;
+    }
+    if line.contains("Heals") {
+        wrap_span(&mut out, base_style, Style::fg(Color::Green), line);
+        return out;
+    }
+
+    let mut remaining = line;
+    loop {
+        let next_marker = MARKERS
+            .iter()
+            .filter_map(|(needle, style)| remaining.find(needle).map(|pos| (pos, *needle, *style)))
+            .min_by_key(|(pos, _, _)| *pos);
+
+        match next_marker {
+            Some((pos, needle, style)) => {
+                out.push_str(&remaining[..pos]);
+                wrap_span(&mut out, base_style, style, needle);
+                remaining = &remaining[pos + needle.len()..];
+            }
+            None => {
+                out.push_str(remaining);
+                break;
+            }
+        }
+    }
+    out
+}
+
+fn line_base_style(line: &str) -> Style {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("# Round")
+        || trimmed.starts_with("--- Round")
+        || trimmed.starts_with("## ")
+        || trimmed.starts_with("### Round")
+    {
+        Style::bold()
+    } else if line.contains("falls unconscious") {
+        Style::bold_fg(Color::Red)
+    } else {
+        Style::PLAIN
+    }
+}
+
+/// Render a full combat log for terminal output. When `color` is `false` the lines pass
+/// through unchanged (the `--no-color` / piped-output path).
+pub fn colorize_log(lines: &[String], color: bool) -> Vec<String> {
+    if !color {
+        return lines.to_vec();
+    }
+    lines
+        .iter()
+        .map(|line| {
+            let base = line_base_style(line);
+            let mut rendered = colorize_markers(line, base);
+            rendered.push_str("\x1b[0m"); // Defensive: never let a line's style escape it.
+            rendered
+        })
+        .collect()
+}
+
+/// Whether color should be used by default for the current process: stdout is a TTY and
+/// the caller hasn't passed `--no-color`.
+pub fn color_enabled(no_color_flag: bool) -> bool {
+    !no_color_flag && std::io::stdout().is_terminal()
+}
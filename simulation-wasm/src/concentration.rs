@@ -0,0 +1,41 @@
+// Data-driven metadata for concentration templates, replacing the `"Hunter's Mark" | "Hex"`
+// / `"Bless" | "Bane"` string matches that used to live inline in `simulation::execute_turn`
+// and `simulation::is_concentration_action`. Adding a new concentration spell to the
+// template data now only needs an entry in `concentration_registry`, not an engine change.
+use crate::model::Combattant;
+
+/// Static metadata describing one concentration template's re-cast behavior.
+#[derive(Debug, Clone, Copy)]
+pub struct ConcentrationSpec {
+    /// Whether the actor may recast this template onto a new target instead of being
+    /// locked out while still concentrating — as long as the current cast is no longer
+    /// `still_worth_keeping`. Non-moveable templates (e.g. Bless) always block a recast.
+    pub moveable: bool,
+    /// `true` if this template's buff/debuff lands on enemies (e.g. Hex); `false` for
+    /// ally-targeted effects (e.g. Bless, which can land on several allies at once).
+    pub targets_enemies: bool,
+}
+
+impl ConcentrationSpec {
+    /// Whether the actor's current concentration cast (identified by `buff_id`) is still
+    /// worth keeping. For an enemy-targeted effect this means the marked enemy is still
+    /// alive; for an ally-targeted effect it means at least one buffed ally still is —
+    /// generalizing the old single-marked-enemy assumption to multi-target buffs.
+    pub fn still_worth_keeping(&self, buff_id: &str, allies: &[Combattant], enemies: &[Combattant]) -> bool {
+        let side = if self.targets_enemies { enemies } else { allies };
+        side.iter()
+            .any(|c| c.final_state.buffs.contains_key(buff_id) && c.final_state.current_hp > 0)
+    }
+}
+
+/// Look up a template's concentration metadata by name. `None` means either the template
+/// isn't a concentration effect at all, or (conservatively) that it hasn't been registered
+/// yet — callers should treat an unregistered name as "not moveable" rather than guessing.
+pub fn concentration_registry(template_name: &str) -> Option<ConcentrationSpec> {
+    match template_name {
+        "Hunter's Mark" | "Hex" => Some(ConcentrationSpec { moveable: true, targets_enemies: true }),
+        "Bless" => Some(ConcentrationSpec { moveable: false, targets_enemies: false }),
+        "Bane" => Some(ConcentrationSpec { moveable: false, targets_enemies: true }),
+        _ => None,
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::events::{Event, EventBus};
 use crate::resources::{ResourceLedger, ResourceType, ResetType, ActionCost};
-use crate::model::{Action, Combattant};
+use crate::model::{Action, Buff, Combattant};
 use crate::enums::CreatureCondition;
 
 /// Central context that maintains all game state during a combat encounter
@@ -53,7 +53,7 @@ pub struct ActiveEffect {
 /// Types of effects that can be applied
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EffectType {
-    Buff(String), // Effect identifier
+    Buff(Buff),
     DamageOverTime { damage_per_round: f64, damage_type: String },
     HealingOverTime { healing_per_round: f64 },
     Condition(CreatureCondition),
@@ -223,7 +223,20 @@ impl TurnContext {
     }
 
     /// Apply an active effect to a target
+    ///
+    /// Enforces one-concentration-at-a-time: installing a new concentration buff from a given
+    /// source automatically drops whatever that source was previously concentrating on.
     pub fn apply_effect(&mut self, effect: ActiveEffect) {
+        if is_concentration_buff(&effect.effect_type) {
+            let previous_ids: Vec<String> = self.active_effects.iter()
+                .filter(|(_, e)| e.source_id == effect.source_id && is_concentration_buff(&e.effect_type))
+                .map(|(id, _)| id.clone())
+                .collect();
+            for id in previous_ids {
+                self.active_effects.remove(&id);
+            }
+        }
+
         // Emit effect application event
         self.event_bus.emit_event(Event::Custom {
             event_type: "EffectApplied".to_string(),
@@ -240,6 +253,78 @@ impl TurnContext {
         self.active_effects.insert(effect.id.clone(), effect);
     }
 
+    /// Apply damage to `target_id` (HP floored at 0), emitting `DamageTaken`. No concentration
+    /// save is rolled here: callers such as `ActionResolver` roll it against
+    /// `concentration_save_dc` and call `break_concentration` on a failure.
+    pub fn apply_damage(&mut self, target_id: &str, damage: f64, damage_type: &str, _source_id: &str) -> Vec<Event> {
+        let mut events = Vec::new();
+
+        let Some(combatant) = self.combatants.get_mut(target_id) else {
+            return events;
+        };
+        combatant.current_hp = (combatant.current_hp - damage).max(0.0);
+
+        events.push(Event::DamageTaken {
+            target_id: target_id.to_string(),
+            damage,
+            damage_type: damage_type.to_string(),
+        });
+
+        events
+    }
+
+    /// Apply healing (or temporary HP) to `target_id`, returning the resulting event.
+    pub fn apply_healing(&mut self, target_id: &str, amount: f64, is_temp_hp: bool, source_id: &str) -> Event {
+        if let Some(combatant) = self.combatants.get_mut(target_id) {
+            if is_temp_hp {
+                combatant.temp_hp = combatant.temp_hp.max(amount);
+            } else {
+                let max_hp = combatant.base_combatant.creature.hp;
+                combatant.current_hp = (combatant.current_hp + amount).min(max_hp);
+            }
+        }
+
+        Event::HealingApplied {
+            target_id: target_id.to_string(),
+            amount,
+            source_id: source_id.to_string(),
+        }
+    }
+
+    /// Whether `combatant_id` currently has any active concentration buff running.
+    pub fn is_concentrating(&self, combatant_id: &str) -> bool {
+        self.active_effects
+            .values()
+            .any(|e| e.source_id == combatant_id && is_concentration_buff(&e.effect_type))
+    }
+
+    /// DC for a concentration save against `damage` taken, per the standard 5e formula.
+    pub fn concentration_save_dc(damage: f64) -> f64 {
+        (damage / 2.0).floor().max(10.0)
+    }
+
+    /// Break every concentration effect sourced from `combatant_id`, emitting
+    /// `ConcentrationBroken` if anything was actually removed.
+    pub fn break_concentration(&mut self, combatant_id: &str) -> Vec<Event> {
+        let broken_ids: Vec<String> = self.active_effects.iter()
+            .filter(|(_, e)| e.source_id == combatant_id && is_concentration_buff(&e.effect_type))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        if broken_ids.is_empty() {
+            return Vec::new();
+        }
+
+        for id in &broken_ids {
+            self.active_effects.remove(id);
+        }
+
+        vec![Event::ConcentrationBroken {
+            caster_id: combatant_id.to_string(),
+            reason: "damage".to_string(),
+        }]
+    }
+
     /// Update all active effects (called at end of turn)
     pub fn update_effects(&mut self) {
         let mut effects_to_remove = Vec::new();
@@ -353,6 +438,11 @@ impl TurnContext {
     }
 }
 
+/// Whether `effect_type` is a buff that requires concentration to maintain.
+fn is_concentration_buff(effect_type: &EffectType) -> bool {
+    matches!(effect_type, EffectType::Buff(buff) if buff.concentration)
+}
+
 /// Statistics about the turn context
 #[derive(Debug, Clone)]
 pub struct ContextStats {
@@ -1,5 +1,195 @@
+use crate::aggregation::ScoreConfig;
 use crate::model::*;
+use crate::simulation;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+
+/// A typed bucket a `SerializableResourceLedger` key (see `resources::ResourceType::to_key`)
+/// can fall into for attrition reporting. Keeps "spent a spell slot" distinguishable from
+/// "took HP damage" instead of collapsing everything into one effective-HP number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceClass {
+    HitPoints,
+    SpellSlot(u8),
+    ClassResource,
+    HitDice,
+}
+
+impl ResourceClass {
+    /// Stable string label for serializing a `HashMap<ResourceClass, _>` as a
+    /// `HashMap<String, _>` on the wire, since `serde_json`/`serde_wasm_bindgen` can't key a
+    /// map by a non-string enum directly.
+    fn label(&self) -> String {
+        match self {
+            ResourceClass::HitPoints => "hit_points".to_string(),
+            ResourceClass::SpellSlot(level) => format!("spell_slot_{}", level),
+            ResourceClass::ClassResource => "class_resources".to_string(),
+            ResourceClass::HitDice => "hit_dice".to_string(),
+        }
+    }
+
+    /// How much one fully-burned unit of this class counts toward encounter "intensity",
+    /// relative to a unit of HP. Burning a class resource or a higher-level spell slot reads
+    /// as more intense than chip HP damage of the same nominal fraction.
+    fn intensity_weight(&self) -> f64 {
+        match self {
+            ResourceClass::HitPoints => 1.0,
+            ResourceClass::HitDice => 1.0,
+            ResourceClass::ClassResource => 1.5,
+            ResourceClass::SpellSlot(level) => 1.0 + 0.3 * (*level as f64),
+        }
+    }
+}
+
+/// Classify a `SerializableResourceLedger` key produced by `resources::ResourceType::to_key`
+/// into a `ResourceClass`, or `None` for resources that aren't attrition (Action/BonusAction/
+/// Reaction/Movement/ItemCharge/Custom) and so don't count toward the breakdown.
+fn classify_resource_key(key: &str) -> Option<ResourceClass> {
+    if key == "HP" {
+        return Some(ResourceClass::HitPoints);
+    }
+    if let Some(inner) = key.strip_prefix("SpellSlot(").and_then(|s| s.strip_suffix(')')) {
+        return inner.parse::<u8>().ok().map(ResourceClass::SpellSlot);
+    }
+    if key.starts_with("ClassResource(") {
+        return Some(ResourceClass::ClassResource);
+    }
+    if key.starts_with("HitDice(") {
+        return Some(ResourceClass::HitDice);
+    }
+    None
+}
+
+/// Sum of (burned, max) per `ResourceClass` across every player combatant's final resource
+/// ledger in a run's last recorded round, so `calculate_decile_stats` can average fractions
+/// across a whole decile's runs.
+fn run_resource_burn(run: &SimulationResult) -> HashMap<ResourceClass, (f64, f64)> {
+    let mut totals: HashMap<ResourceClass, (f64, f64)> = HashMap::new();
+
+    let Some(round) = run.encounters.last().and_then(|enc| enc.rounds.last()) else {
+        return totals;
+    };
+
+    for combatant in &round.team1 {
+        for (key, &max_val) in &combatant.final_state.resources.max {
+            let Some(class) = classify_resource_key(key) else { continue; };
+            if max_val <= 0.0 {
+                continue;
+            }
+            let current_val = combatant.final_state.resources.current.get(key).copied().unwrap_or(0.0);
+            let burned = (max_val - current_val).max(0.0);
+            let entry = totals.entry(class).or_insert((0.0, 0.0));
+            entry.0 += burned;
+            entry.1 += max_val;
+        }
+    }
+
+    totals
+}
+
+/// Turn a (burned, max) totals map into the `label -> fraction` map `DecileStats` exposes.
+fn resource_burn_fractions(totals: &HashMap<ResourceClass, (f64, f64)>) -> HashMap<String, f64> {
+    totals
+        .iter()
+        .filter(|(_, (_, max))| *max > 0.0)
+        .map(|(class, (burned, max))| (class.label(), burned / max))
+        .collect()
+}
+
+/// Weighted-average burned fraction across every resource class present, weighting each
+/// class's fraction by `ResourceClass::intensity_weight() * max` so a party with a bigger
+/// spell-slot pool isn't under-weighted relative to one with a smaller pool. Returns `None`
+/// if no resource data was tracked at all, so callers can fall back to plain HP-lost.
+fn weighted_resource_intensity(totals: &HashMap<ResourceClass, (f64, f64)>) -> Option<f64> {
+    if totals.is_empty() {
+        return None;
+    }
+    let mut weighted_burned = 0.0;
+    let mut weighted_max = 0.0;
+    for (class, (burned, max)) in totals {
+        let weight = class.intensity_weight();
+        weighted_burned += burned * weight;
+        weighted_max += max * weight;
+    }
+    if weighted_max <= 0.0 {
+        return None;
+    }
+    Some((weighted_burned / weighted_max) * 100.0)
+}
+
+/// The single resource pool that drained the most, weighted by `ResourceClass::intensity_weight()`
+/// so a party that ends a run at full HP but zero spell slots is correctly flagged as bottlenecked
+/// on spell slots rather than on HP. HP competes on equal footing via an implicit weight of 1.0.
+/// Returns the winning pool's `ResourceClass::label()` and its weighted drain as a 0-100 percentage.
+fn dominant_resource_drain(hp_lost_fraction: f64, totals: &HashMap<ResourceClass, (f64, f64)>) -> (String, f64) {
+    let mut best_label = ResourceClass::HitPoints.label();
+    let mut best_weighted_fraction = hp_lost_fraction.clamp(0.0, 1.0);
+
+    for (class, (burned, max)) in totals {
+        if *max <= 0.0 {
+            continue;
+        }
+        let weighted_fraction = (burned / max).clamp(0.0, 1.0) * class.intensity_weight();
+        if weighted_fraction > best_weighted_fraction {
+            best_weighted_fraction = weighted_fraction;
+            best_label = class.label();
+        }
+    }
+
+    (best_label, (best_weighted_fraction * 100.0).min(100.0))
+}
+
+/// Averages each decile's `resource_burned_fraction` (plus its HP-lost fraction, under the
+/// `"hit_points"` key) across the whole result set, giving a scenario-wide drain breakdown
+/// independent of any single decile - used for `AggregateOutput::resource_drain_breakdown`.
+fn aggregate_resource_breakdown(deciles: &[DecileStats]) -> HashMap<String, f64> {
+    let mut sums: HashMap<String, f64> = HashMap::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for decile in deciles {
+        *sums.entry(ResourceClass::HitPoints.label()).or_insert(0.0) += decile.hp_lost_percent / 100.0;
+        *counts.entry(ResourceClass::HitPoints.label()).or_insert(0) += 1;
+        for (label, fraction) in &decile.resource_burned_fraction {
+            *sums.entry(label.clone()).or_insert(0.0) += fraction;
+            *counts.entry(label.clone()).or_insert(0) += 1;
+        }
+    }
+
+    sums.into_iter()
+        .map(|(label, sum)| {
+            let n = counts[&label] as f64;
+            (label, sum / n)
+        })
+        .collect()
+}
+
+/// 95% bootstrap confidence interval (2.5th/97.5th percentile of 1000 resamples-with-replacement)
+/// for the mean of `values`. Uses its own deterministically-seeded RNG, independent of the
+/// shared simulation RNG in `rng.rs`, since this is post-hoc statistics rather than a combat
+/// roll. Degenerates to `(v, v)` when `values` has 0 or 1 elements - no meaningful spread to report.
+fn bootstrap_ci(values: &[f64], seed: u64) -> (f64, f64) {
+    if values.len() <= 1 {
+        let v = values.first().copied().unwrap_or(0.0);
+        return (v, v);
+    }
+
+    const RESAMPLES: usize = 1000;
+    let n = values.len();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut means: Vec<f64> = (0..RESAMPLES)
+        .map(|_| (0..n).map(|_| values[rng.gen_range(0..n)]).sum::<f64>() / n as f64)
+        .collect();
+
+    means.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let lo_idx = ((RESAMPLES as f64) * 0.025).floor() as usize;
+    let hi_idx = (((RESAMPLES as f64) * 0.975).ceil() as usize).min(RESAMPLES - 1);
+    (means[lo_idx], means[hi_idx])
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum SafetyGrade {
@@ -84,6 +274,22 @@ pub struct CombatantVisualization {
     pub hp_percentage: f64,
 }
 
+/// Per-combatant damage-dealt/taken/threat report for one run's final encounter - see
+/// `calculate_combatant_contributions`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CombatantContribution {
+    pub id: String,
+    pub name: String,
+    pub is_player: bool,
+    pub damage_dealt: f64,
+    pub damage_taken: f64,
+    pub rounds_downed: usize,
+    /// `damage_dealt + damage_taken * 0.5` - surfaces the combatants who both hit hard and
+    /// absorbed hits, not just the highest-damage dealer.
+    pub threat_score: f64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DecileStats {
@@ -97,6 +303,36 @@ pub struct DecileStats {
     pub median_run_visualization: Vec<CombatantVisualization>,
     pub median_run_data: Option<EncounterResult>,
     pub battle_duration_rounds: usize,
+    /// Average burned-fraction (0.0-1.0) per resource class across this decile's runs, keyed
+    /// by `ResourceClass::label()` (e.g. "hit_points", "spell_slot_3", "class_resources",
+    /// "hit_dice"). Classes nobody in this decile had registered are simply absent.
+    pub resource_burned_fraction: HashMap<String, f64>,
+    /// `resource_burned_fraction`'s classes combined with `ResourceClass::intensity_weight()`,
+    /// as a 0-100 percentage. Reported alongside the tier; `assess_intensity_tier` does not
+    /// read it (the tier comes from `dominant_drain_percent` blended with monster pressure).
+    pub weighted_resource_intensity_percent: Option<f64>,
+    /// Average `run_monster_pressure` across this decile's runs - already normalized by
+    /// party size, but unbounded; `assess_intensity_tier` squashes it via `pressure_to_percent`
+    /// before blending it into the tier classification.
+    pub avg_monster_pressure: f64,
+    /// `ResourceClass::label()` of the pool that drained the most this decile (HP included,
+    /// as `"hit_points"`) - see `dominant_resource_drain`. The tier boundaries key off this
+    /// pool's drain rather than averaging every pool together, so a caster out of spell slots
+    /// at full HP still reads as a high-intensity decile.
+    pub dominant_drain_resource: String,
+    /// Weighted 0-100 drain percentage of `dominant_drain_resource`.
+    pub dominant_drain_percent: f64,
+    /// 95% bootstrap CI for `win_rate` (0-100 scale, same units) - see `bootstrap_ci`. A single
+    /// representative run (e.g. `global_median`) has a degenerate `(win_rate, win_rate)` CI.
+    pub win_rate_ci: (f64, f64),
+    /// 95% bootstrap CI for `median_survivors` (same units, as `f64`).
+    pub median_survivors_ci: (f64, f64),
+    /// Per-combatant damage-dealt/taken/threat report for the representative run's final
+    /// encounter, ranked by `CombatantContribution::threat_score` - see
+    /// `calculate_combatant_contributions`. Empty unless populated via
+    /// `run_decile_analysis_with_contributions`, which alone has access to the event log this
+    /// report is built from; plain `SimulationResult`s carry none.
+    pub combatant_contributions: Vec<CombatantContribution>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -112,6 +348,16 @@ pub struct AggregateOutput {
     pub analysis_summary: String,
     pub tuning_suggestions: Vec<String>,
     pub is_good_design: bool,
+    /// Scenario-wide average drain fraction (0.0-1.0) per resource pool, keyed by
+    /// `ResourceClass::label()` (HP included as `"hit_points"`) - see `aggregate_resource_breakdown`.
+    pub resource_drain_breakdown: HashMap<String, f64>,
+    /// The pool that bottlenecked the encounter overall, taken from the representative
+    /// (global-median, or mid-deciles fallback) run's `dominant_drain_resource`.
+    pub bottleneck_resource: Option<String>,
+    /// Fraction (0.0-1.0) of all player HP lost attributable to each attacker damage type,
+    /// keyed by `events::Event::DamageTaken`'s `damage_type` string. Empty unless computed via
+    /// `run_decile_analysis_with_damage_breakdown` - plain `SimulationResult`s carry no event log.
+    pub damage_type_breakdown: HashMap<String, f64>,
 }
 
 fn extract_combatant_visualization(result: &SimulationResult) -> (Vec<CombatantVisualization>, usize) {
@@ -197,7 +443,15 @@ fn assess_intensity_tier(deciles: &[DecileStats], global_median: &Option<DecileS
     if deciles.is_empty() { return IntensityTier::Tier1; }
     
     let typical = global_median.as_ref().or_else(|| deciles.get(deciles.len() / 2)).unwrap_or(&deciles[0]);
-    let resources_left = 100.0 - typical.hp_lost_percent;
+    // Key off whichever single pool drained the most (`dominant_drain_percent`) rather than
+    // averaging every pool together, so a caster who ends at full HP but zero spell slots still
+    // lands in a high tier instead of being diluted by the party's untouched HP.
+    let attrition_percent = typical.dominant_drain_percent;
+    // Blend in how much monster value the party had to chew through, so grinding down
+    // high-threat monsters reads as more intense than mopping up trivial ones at the same attrition.
+    let intensity_percent =
+        0.7 * attrition_percent + 0.3 * pressure_to_percent(typical.avg_monster_pressure);
+    let resources_left = 100.0 - intensity_percent;
 
     if resources_left > 90.0 { IntensityTier::Tier1 }
     else if resources_left >= 70.0 { IntensityTier::Tier2 }
@@ -242,8 +496,17 @@ fn generate_analysis_summary(grade: &SafetyGrade, tier: &IntensityTier, deciles:
         IntensityTier::Tier5 => "Players will end with empty tanks.",
     };
 
-    format!("Grade {}: {} | {}: {} | Typical Survivors: {}/{}",
-        grade, safety_desc, tier, intensity_desc, typical.median_survivors, typical.party_size)
+    let mut summary = format!("Grade {}: {} | {}: {} | Typical Survivors: {}/{}",
+        grade, safety_desc, tier, intensity_desc, typical.median_survivors, typical.party_size);
+
+    // The CI crossing 0 survivors means the sampled runs don't yet rule out a TPK at the
+    // "typical" decile, even though the point estimate itself looks safe - the grade could
+    // shift once more runs are collected.
+    if typical.median_survivors > 0 && typical.median_survivors_ci.0 <= 0.0 {
+        summary.push_str(" | Warning: survivor CI crosses 0 - run more iterations before trusting this grade.");
+    }
+
+    summary
 }
 
 fn generate_tuning_suggestions(grade: &SafetyGrade, tier: &IntensityTier, _deciles: &[DecileStats]) -> Vec<String> {
@@ -262,28 +525,220 @@ fn generate_tuning_suggestions(grade: &SafetyGrade, tier: &IntensityTier, _decil
     suggestions
 }
 
-fn calculate_run_stats(run: &SimulationResult, party_size: usize) -> (f64, f64, usize, usize) {
-    let score = crate::aggregation::calculate_score(run);
-    let survivors = ((score / 1_000_000.0).floor() as usize).min(party_size);
-    
+/// Same as `generate_tuning_suggestions`, plus a concrete call-out when a single damage type
+/// accounts for more than half of all player HP lost - see `damage_type_breakdown`.
+fn generate_tuning_suggestions_with_damage_breakdown(
+    grade: &SafetyGrade,
+    tier: &IntensityTier,
+    deciles: &[DecileStats],
+    damage_type_breakdown: &HashMap<String, f64>,
+) -> Vec<String> {
+    let mut suggestions = generate_tuning_suggestions(grade, tier, deciles);
+    if let Some((dtype, fraction)) = damage_type_breakdown
+        .iter()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+    {
+        if *fraction > 0.5 {
+            suggestions.push(format!(
+                "{:.0}% of all party HP lost came from {} damage. Consider a resistance, a typed-damage reduction, or diversifying monster damage types.",
+                fraction * 100.0,
+                dtype
+            ));
+        }
+    }
+    suggestions
+}
+
+/// Sums player HP lost to each attacker `damage_type` across every run, from
+/// `events::Event::DamageTaken` entries targeting a `team1` combatant, and returns it as a
+/// fraction (0.0-1.0) of the total player HP lost across all runs supplied. Empty if no runs
+/// carried any player-damage events.
+pub fn damage_type_breakdown(runs: &[SimulationRun]) -> HashMap<String, f64> {
+    let mut totals: HashMap<String, f64> = HashMap::new();
+    let mut grand_total = 0.0;
+
+    for run in runs {
+        let player_ids: std::collections::HashSet<&str> = run
+            .result
+            .encounters
+            .iter()
+            .flat_map(|e| e.rounds.iter())
+            .flat_map(|r| r.team1.iter())
+            .map(|c| c.id.as_str())
+            .collect();
+
+        for event in &run.events {
+            if let crate::events::Event::DamageTaken { target_id, damage, damage_type } = event {
+                if player_ids.contains(target_id.as_str()) {
+                    *totals.entry(damage_type.clone()).or_insert(0.0) += damage;
+                    grand_total += damage;
+                }
+            }
+        }
+    }
+
+    if grand_total <= 0.0 {
+        return HashMap::new();
+    }
+
+    totals.into_iter().map(|(dtype, total)| (dtype, total / grand_total)).collect()
+}
+
+/// Walks `run`'s final encounter and attributes, per combatant, total damage dealt/taken (from
+/// `events::Event::AttackHit` entries), rounds spent downed (`final_state.current_hp == 0`), and
+/// a derived threat score - see `CombatantContribution`. Ranked descending by `threat_score`.
+/// Empty if `run` has no encounters.
+pub fn calculate_combatant_contributions(run: &SimulationRun) -> Vec<CombatantContribution> {
+    let Some(encounter) = run.result.encounters.last() else {
+        return Vec::new();
+    };
+    let Some(last_round) = encounter.rounds.last() else {
+        return Vec::new();
+    };
+
+    let mut contributions: HashMap<String, CombatantContribution> = HashMap::new();
+    for combattant in &last_round.team1 {
+        contributions.insert(combattant.id.clone(), CombatantContribution {
+            id: combattant.id.clone(),
+            name: combattant.creature.name.clone(),
+            is_player: true,
+            damage_dealt: 0.0,
+            damage_taken: 0.0,
+            rounds_downed: 0,
+            threat_score: 0.0,
+        });
+    }
+    for combattant in &last_round.team2 {
+        contributions.insert(combattant.id.clone(), CombatantContribution {
+            id: combattant.id.clone(),
+            name: combattant.creature.name.clone(),
+            is_player: false,
+            damage_dealt: 0.0,
+            damage_taken: 0.0,
+            rounds_downed: 0,
+            threat_score: 0.0,
+        });
+    }
+
+    for round in &encounter.rounds {
+        for combattant in round.team1.iter().chain(round.team2.iter()) {
+            if combattant.final_state.current_hp == 0 {
+                if let Some(contribution) = contributions.get_mut(&combattant.id) {
+                    contribution.rounds_downed += 1;
+                }
+            }
+        }
+    }
+
+    for event in &run.events {
+        if let crate::events::Event::AttackHit { attacker_id, target_id, damage, .. } = event {
+            if let Some(contribution) = contributions.get_mut(attacker_id) {
+                contribution.damage_dealt += damage;
+            }
+            if let Some(contribution) = contributions.get_mut(target_id) {
+                contribution.damage_taken += damage;
+            }
+        }
+    }
+
+    let mut result: Vec<CombatantContribution> = contributions.into_values().collect();
+    for contribution in &mut result {
+        contribution.threat_score = contribution.damage_dealt + contribution.damage_taken * 0.5;
+    }
+    result.sort_by(|a, b| b.threat_score.partial_cmp(&a.threat_score).unwrap_or(std::cmp::Ordering::Equal));
+    result
+}
+
+/// Per-run outcome feeding decile aggregation.
+struct RunStats {
+    hp_lost: f64,
+    party_max_hp: f64,
+    survivors: usize,
+    duration: usize,
+    /// (burned, max) per `ResourceClass`, summed across the party - see `run_resource_burn`.
+    resource_burn: HashMap<ResourceClass, (f64, f64)>,
+    /// Monster-value-weighted difficulty contribution - see `run_monster_pressure`.
+    monster_pressure: f64,
+}
+
+fn calculate_run_stats(
+    run: &SimulationResult,
+    party_size: usize,
+    config: &ScoreConfig,
+) -> RunStats {
+    let score = crate::aggregation::calculate_score_with_config(run, config);
+    let survivors = ((score / config.survivor_weight).floor() as usize).min(party_size);
+
     let mut run_party_max_hp = 0.0;
     if let Some(enc) = run.encounters.first() {
         if let Some(round) = enc.rounds.first() {
             for c in &round.team1 { run_party_max_hp += c.creature.hp as f64; }
         }
     }
-    let hp_lost = (run_party_max_hp - (score - (survivors as f64 * 1_000_000.0))).max(0.0);
+    let hp_lost = (run_party_max_hp - (score - (survivors as f64 * config.survivor_weight))).max(0.0);
     let duration = run.encounters.iter().map(|e| e.rounds.len()).sum::<usize>();
-    
-    (hp_lost, run_party_max_hp, survivors, duration)
+
+    RunStats {
+        hp_lost,
+        party_max_hp: run_party_max_hp,
+        survivors,
+        duration,
+        resource_burn: run_resource_burn(run),
+        monster_pressure: run_monster_pressure(run, party_size),
+    }
+}
+
+/// Proxy "value" of a monster for threat/difficulty scoring - a CR-ish blend of HP, AC, and
+/// action economy, since this engine has no CR field to read directly.
+fn monster_value(creature: &Creature) -> f64 {
+    creature.hp as f64 + creature.ac as f64 * 2.0 + creature.actions.len() as f64 * 5.0
+}
+
+/// Sum of each `team2` monster's `monster_value` weighted by the fraction of its max HP it
+/// still had in the run's last recorded round - a monster still standing at the end was
+/// actively threatening the party for the whole fight, so it contributes more "pressure" than
+/// one mopped up early, even at identical final party attrition. Normalized by `party_size` so
+/// the result stays comparable across encounters with different party sizes. Zero for a run
+/// with no `team2` entries.
+fn run_monster_pressure(run: &SimulationResult, party_size: usize) -> f64 {
+    if party_size == 0 {
+        return 0.0;
+    }
+    let Some(round) = run.encounters.last().and_then(|enc| enc.rounds.last()) else {
+        return 0.0;
+    };
+
+    let total: f64 = round
+        .team2
+        .iter()
+        .map(|c| {
+            let max_hp = c.creature.hp as f64;
+            if max_hp <= 0.0 {
+                return 0.0;
+            }
+            let survived_fraction = (c.final_state.current_hp as f64 / max_hp).clamp(0.0, 1.0);
+            monster_value(&c.creature) * survived_fraction
+        })
+        .sum();
+
+    total / party_size as f64
+}
+
+/// Squash an unbounded `monster_pressure` value into a 0-100 scale with diminishing returns,
+/// so it can be blended with the already-percentage-scale HP/resource intensity terms without
+/// needing to know the absolute scale monster values happen to live on.
+fn pressure_to_percent(pressure: f64) -> f64 {
+    (pressure / (pressure + 100.0)) * 100.0
 }
 
-fn analyze_results(results: &[SimulationResult], scenario_name: &str, party_size: usize) -> AggregateOutput {
+fn analyze_results(results: &[SimulationResult], scenario_name: &str, party_size: usize, config: &ScoreConfig) -> AggregateOutput {
     if results.is_empty() {
         return AggregateOutput {
             scenario_name: scenario_name.to_string(), total_runs: 0, deciles: Vec::new(), global_median: None,
             safety_grade: SafetyGrade::A, intensity_tier: IntensityTier::Tier1, encounter_label: EncounterLabel::Standard,
             analysis_summary: "No data.".to_string(), tuning_suggestions: Vec::new(), is_good_design: false,
+            resource_drain_breakdown: HashMap::new(), bottleneck_resource: None,
+            damage_type_breakdown: HashMap::new(),
         };
     }
 
@@ -297,20 +752,32 @@ fn analyze_results(results: &[SimulationResult], scenario_name: &str, party_size
     if is_perfect && total_runs >= 11 {
         let median_idx = total_runs / 2;
         let median_run = &results[median_idx];
-        let (hp_lost, max_hp, survivors, duration) = calculate_run_stats(median_run, party_size);
+        let run_stats = calculate_run_stats(median_run, party_size, config);
+        let (hp_lost, max_hp, survivors, duration) = (run_stats.hp_lost, run_stats.party_max_hp, run_stats.survivors, run_stats.duration);
         let (visualization_data, _) = extract_combatant_visualization(median_run);
-        
+        let hp_lost_fraction = if max_hp > 0.0 { hp_lost / max_hp } else { 0.0 };
+        let (dominant_drain_resource, dominant_drain_percent) =
+            dominant_resource_drain(hp_lost_fraction, &run_stats.resource_burn);
+
         global_median = Some(DecileStats {
             decile: 0,
             label: "Global Median".to_string(),
             median_survivors: survivors,
             party_size,
             total_hp_lost: hp_lost,
-            hp_lost_percent: if max_hp > 0.0 { (hp_lost / max_hp) * 100.0 } else { 0.0 },
+            hp_lost_percent: hp_lost_fraction * 100.0,
             win_rate: if survivors > 0 { 100.0 } else { 0.0 },
             median_run_visualization: visualization_data,
             median_run_data: if !median_run.encounters.is_empty() { Some(median_run.encounters[0].clone()) } else { None },
             battle_duration_rounds: duration,
+            resource_burned_fraction: resource_burn_fractions(&run_stats.resource_burn),
+            weighted_resource_intensity_percent: weighted_resource_intensity(&run_stats.resource_burn),
+            avg_monster_pressure: run_stats.monster_pressure,
+            dominant_drain_resource,
+            dominant_drain_percent,
+            win_rate_ci: (if survivors > 0 { 100.0 } else { 0.0 }, if survivors > 0 { 100.0 } else { 0.0 }),
+            median_survivors_ci: (survivors as f64, survivors as f64),
+            combatant_contributions: Vec::new(),
         });
 
         for i in 0..10 {
@@ -318,7 +785,7 @@ fn analyze_results(results: &[SimulationResult], scenario_name: &str, party_size
             let end_idx = start_idx + slice_size;
             if start_idx < total_runs && end_idx <= total_runs {
                 let slice = &results[start_idx..end_idx];
-                deciles.push(calculate_decile_stats(slice, i + 1, party_size));
+                deciles.push(calculate_decile_stats(slice, i + 1, party_size, config));
             }
         }
     } else {
@@ -328,26 +795,38 @@ fn analyze_results(results: &[SimulationResult], scenario_name: &str, party_size
             let end_idx = ((i + 1) as f64 * decile_size).floor() as usize;
             let slice = &results[start_idx..end_idx.min(total_runs)];
             if !slice.is_empty() {
-                deciles.push(calculate_decile_stats(slice, i + 1, party_size));
+                deciles.push(calculate_decile_stats(slice, i + 1, party_size, config));
             }
         }
         
         let median_idx = total_runs / 2;
         if let Some(median_run) = results.get(median_idx) {
-            let (hp_lost, max_hp, survivors, duration) = calculate_run_stats(median_run, party_size);
+            let run_stats = calculate_run_stats(median_run, party_size, config);
+            let (hp_lost, max_hp, survivors, duration) = (run_stats.hp_lost, run_stats.party_max_hp, run_stats.survivors, run_stats.duration);
             let (visualization_data, _) = extract_combatant_visualization(median_run);
-            
+            let hp_lost_fraction = if max_hp > 0.0 { hp_lost / max_hp } else { 0.0 };
+            let (dominant_drain_resource, dominant_drain_percent) =
+                dominant_resource_drain(hp_lost_fraction, &run_stats.resource_burn);
+
             global_median = Some(DecileStats {
                 decile: 0,
                 label: "Global Median".to_string(),
                 median_survivors: survivors,
                 party_size,
                 total_hp_lost: hp_lost,
-                hp_lost_percent: if max_hp > 0.0 { (hp_lost / max_hp) * 100.0 } else { 0.0 },
+                hp_lost_percent: hp_lost_fraction * 100.0,
                 win_rate: if survivors > 0 { 100.0 } else { 0.0 },
                 median_run_visualization: visualization_data,
                 median_run_data: if !median_run.encounters.is_empty() { Some(median_run.encounters[0].clone()) } else { None },
                 battle_duration_rounds: duration,
+                resource_burned_fraction: resource_burn_fractions(&run_stats.resource_burn),
+                weighted_resource_intensity_percent: weighted_resource_intensity(&run_stats.resource_burn),
+                avg_monster_pressure: run_stats.monster_pressure,
+                dominant_drain_resource,
+                dominant_drain_percent,
+                win_rate_ci: (if survivors > 0 { 100.0 } else { 0.0 }, if survivors > 0 { 100.0 } else { 0.0 }),
+                median_survivors_ci: (survivors as f64, survivors as f64),
+                combatant_contributions: Vec::new(),
             });
         }
     }
@@ -358,34 +837,76 @@ fn analyze_results(results: &[SimulationResult], scenario_name: &str, party_size
     let analysis_summary = generate_analysis_summary(&safety_grade, &intensity_tier, &deciles, &global_median);
     let tuning_suggestions = generate_tuning_suggestions(&safety_grade, &intensity_tier, &deciles);
     
-    let is_good_design = matches!(safety_grade, SafetyGrade::A | SafetyGrade::B) && 
+    let is_good_design = matches!(safety_grade, SafetyGrade::A | SafetyGrade::B) &&
                          matches!(intensity_tier, IntensityTier::Tier3 | IntensityTier::Tier4);
 
+    let resource_drain_breakdown = aggregate_resource_breakdown(&deciles);
+    let bottleneck_resource = global_median.as_ref()
+        .or_else(|| deciles.get(deciles.len() / 2))
+        .map(|d| d.dominant_drain_resource.clone());
+
     AggregateOutput {
         scenario_name: scenario_name.to_string(), total_runs, deciles, global_median,
         safety_grade, intensity_tier, encounter_label, analysis_summary, tuning_suggestions, is_good_design,
+        resource_drain_breakdown, bottleneck_resource,
+        damage_type_breakdown: HashMap::new(),
     }
 }
 
-fn calculate_decile_stats(slice: &[SimulationResult], decile_num: usize, party_size: usize) -> DecileStats {
+fn calculate_decile_stats(slice: &[SimulationResult], decile_num: usize, party_size: usize, config: &ScoreConfig) -> DecileStats {
+    // `calculate_run_stats` re-walks every encounter/round/combatant in a run, so with
+    // thousands of runs per decile this is the hot path - farm it out across rayon's
+    // thread pool on native targets, same fallback convention `simulation::run_monte_carlo`
+    // uses for the WASM/single-threaded target, then fold the (cheap) per-run tuples serially.
+    #[cfg(not(target_arch = "wasm32"))]
+    let per_run_stats: Vec<RunStats> = slice
+        .par_iter()
+        .map(|run| calculate_run_stats(run, party_size, config))
+        .collect();
+
+    #[cfg(target_arch = "wasm32")]
+    let per_run_stats: Vec<RunStats> = slice
+        .iter()
+        .map(|run| calculate_run_stats(run, party_size, config))
+        .collect();
+
     let mut total_wins = 0.0;
     let mut total_hp_lost = 0.0;
     let mut total_survivors = 0;
     let mut total_duration = 0;
     let mut total_party_max_hp = 0.0;
+    let mut total_resource_burn: HashMap<ResourceClass, (f64, f64)> = HashMap::new();
+    let mut total_monster_pressure = 0.0;
+    let mut wins: Vec<f64> = Vec::with_capacity(per_run_stats.len());
+    let mut survivor_counts: Vec<f64> = Vec::with_capacity(per_run_stats.len());
 
-    for run in slice {
-        let (hp_lost, max_hp, survivors, duration) = calculate_run_stats(run, party_size);
-        if survivors > 0 { total_wins += 1.0; }
-        total_survivors += survivors;
-        total_hp_lost += hp_lost;
-        total_party_max_hp += max_hp;
-        total_duration += duration;
+    for run_stats in &per_run_stats {
+        if run_stats.survivors > 0 { total_wins += 1.0; }
+        total_survivors += run_stats.survivors;
+        total_hp_lost += run_stats.hp_lost;
+        total_party_max_hp += run_stats.party_max_hp;
+        total_duration += run_stats.duration;
+        total_monster_pressure += run_stats.monster_pressure;
+        wins.push(if run_stats.survivors > 0 { 1.0 } else { 0.0 });
+        survivor_counts.push(run_stats.survivors as f64);
+        for (class, (burned, max)) in &run_stats.resource_burn {
+            let entry = total_resource_burn.entry(*class).or_insert((0.0, 0.0));
+            entry.0 += burned;
+            entry.1 += max;
+        }
     }
 
+    // Seeded from the decile's identity and size, not wall-clock - keeps the analysis
+    // deterministic/reproducible like the rest of this module.
+    let ci_seed = (decile_num as u64).wrapping_mul(1_000_003).wrapping_add(slice.len() as u64);
+    let (win_rate_ci_lo, win_rate_ci_hi) = bootstrap_ci(&wins, ci_seed);
+    let win_rate_ci = (win_rate_ci_lo * 100.0, win_rate_ci_hi * 100.0);
+    let median_survivors_ci = bootstrap_ci(&survivor_counts, ci_seed.wrapping_add(1));
+
     let count = slice.len() as f64;
     let avg_hp_lost = if count > 0.0 { total_hp_lost / count } else { 0.0 };
     let avg_party_max_hp = if count > 0.0 { total_party_max_hp / count } else { 0.0 };
+    let avg_monster_pressure = if count > 0.0 { total_monster_pressure / count } else { 0.0 };
 
     let median_in_slice_idx = slice.len() / 2;
     let median_run = &slice[median_in_slice_idx];
@@ -397,47 +918,509 @@ fn calculate_decile_stats(slice: &[SimulationResult], decile_num: usize, party_s
         _ => "Decile",
     };
 
+    let hp_lost_percent = if avg_party_max_hp > 0.0 { (avg_hp_lost / avg_party_max_hp) * 100.0 } else { 0.0 };
+    let (dominant_drain_resource, dominant_drain_percent) =
+        dominant_resource_drain(hp_lost_percent / 100.0, &total_resource_burn);
+
     DecileStats {
         decile: decile_num,
         label: format!("{} {}", label, decile_num),
         median_survivors: if count > 0.0 { (total_survivors as f64 / count).round() as usize } else { 0 },
         party_size,
         total_hp_lost: avg_hp_lost,
-        hp_lost_percent: if avg_party_max_hp > 0.0 { (avg_hp_lost / avg_party_max_hp) * 100.0 } else { 0.0 },
+        hp_lost_percent,
         win_rate: if count > 0.0 { (total_wins / count) * 100.0 } else { 0.0 },
         median_run_visualization: visualization_data,
         median_run_data: if !median_run.encounters.is_empty() { Some(median_run.encounters[0].clone()) } else { None },
         battle_duration_rounds: if count > 0.0 { (total_duration as f64 / count).round() as usize } else { 0 },
+        resource_burned_fraction: resource_burn_fractions(&total_resource_burn),
+        weighted_resource_intensity_percent: weighted_resource_intensity(&total_resource_burn),
+        avg_monster_pressure,
+        dominant_drain_resource,
+        dominant_drain_percent,
+        win_rate_ci,
+        median_survivors_ci,
+        combatant_contributions: Vec::new(),
     }
 }
 
 pub fn run_decile_analysis(results: &[SimulationResult], scenario_name: &str, party_size: usize) -> AggregateOutput {
-    analyze_results(results, scenario_name, party_size)
+    run_decile_analysis_with_config(results, scenario_name, party_size, &ScoreConfig::default())
+}
+
+/// Same as `run_decile_analysis`, but decodes scores using `config` instead of
+/// `ScoreConfig::default()`. `config` must be the same weights the runs were scored with (or
+/// scores were sorted/selected with) upstream, or the decoded survivor/HP-lost figures won't
+/// correspond to the scores that actually picked the median/decile runs.
+pub fn run_decile_analysis_with_config(results: &[SimulationResult], scenario_name: &str, party_size: usize, config: &ScoreConfig) -> AggregateOutput {
+    analyze_results(results, scenario_name, party_size, config)
+}
+
+/// Same as `run_decile_analysis`, but additionally takes each run's event log (paired up as
+/// `SimulationRun`) so `damage_type_breakdown` can be computed and folded into
+/// `AggregateOutput::damage_type_breakdown` and `tuning_suggestions`. Plain `SimulationResult`s
+/// carry no event log, so this is a separate entry point rather than a change to the others.
+pub fn run_decile_analysis_with_damage_breakdown(runs: &[SimulationRun], scenario_name: &str, party_size: usize) -> AggregateOutput {
+    let results: Vec<SimulationResult> = runs.iter().map(|r| r.result.clone()).collect();
+    let mut output = analyze_results(&results, scenario_name, party_size, &ScoreConfig::default());
+
+    output.damage_type_breakdown = damage_type_breakdown(runs);
+    output.tuning_suggestions = generate_tuning_suggestions_with_damage_breakdown(
+        &output.safety_grade,
+        &output.intensity_tier,
+        &output.deciles,
+        &output.damage_type_breakdown,
+    );
+
+    output
+}
+
+/// Same as `run_decile_analysis`, but additionally takes each run's event log (paired up as
+/// `SimulationRun`) so `AggregateOutput::global_median.combatant_contributions` can be populated
+/// from the median run's `calculate_combatant_contributions`. Uses the same `total_runs / 2`
+/// median index as `analyze_results` itself rather than its fuller `is_perfect` selection logic,
+/// since that logic only picks among `SimulationResult`s and has no reference back to which
+/// `SimulationRun` (with events) it chose - a known, minor approximation. Plain
+/// `SimulationResult`s carry no event log, so this is a separate entry point rather than a
+/// change to the others.
+pub fn run_decile_analysis_with_contributions(runs: &[SimulationRun], scenario_name: &str, party_size: usize) -> AggregateOutput {
+    let results: Vec<SimulationResult> = runs.iter().map(|r| r.result.clone()).collect();
+    let mut output = analyze_results(&results, scenario_name, party_size, &ScoreConfig::default());
+
+    if let (Some(global_median), Some(median_run)) = (output.global_median.as_mut(), runs.get(runs.len() / 2)) {
+        global_median.combatant_contributions = calculate_combatant_contributions(median_run);
+    }
+
+    output
 }
 
 pub fn run_day_analysis(results: &[SimulationResult], scenario_name: &str, party_size: usize) -> AggregateOutput {
-    analyze_results(results, scenario_name, party_size)
+    run_day_analysis_with_config(results, scenario_name, party_size, &ScoreConfig::default())
+}
+
+pub fn run_day_analysis_with_config(results: &[SimulationResult], scenario_name: &str, party_size: usize, config: &ScoreConfig) -> AggregateOutput {
+    analyze_results(results, scenario_name, party_size, config)
 }
 
 pub fn run_encounter_analysis(results: &[SimulationResult], encounter_idx: usize, scenario_name: &str, party_size: usize) -> AggregateOutput {
+    run_encounter_analysis_with_config(results, encounter_idx, scenario_name, party_size, &ScoreConfig::default())
+}
+
+pub fn run_encounter_analysis_with_config(results: &[SimulationResult], encounter_idx: usize, scenario_name: &str, party_size: usize, config: &ScoreConfig) -> AggregateOutput {
     let mut encounter_results: Vec<SimulationResult> = results.iter()
         .filter_map(|run| {
-            if encounter_idx < run.encounters.len() { 
-                Some(SimulationResult { 
+            if encounter_idx < run.encounters.len() {
+                Some(SimulationResult {
                     encounters: vec![run.encounters[encounter_idx].clone()],
-                    score: run.score 
-                }) 
-            } else { 
-                None 
+                    score: run.score
+                })
+            } else {
+                None
             }
         })
         .collect();
 
     encounter_results.sort_by(|a, b| {
-        let score_a = crate::aggregation::calculate_score(a);
-        let score_b = crate::aggregation::calculate_score(b);
+        let score_a = crate::aggregation::calculate_score_with_config(a, config);
+        let score_b = crate::aggregation::calculate_score_with_config(b, config);
         score_a.partial_cmp(&score_b).unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    analyze_results(&encounter_results, scenario_name, party_size)
+    analyze_results(&encounter_results, scenario_name, party_size, config)
+}
+
+/// The ranges to sweep in `run_sweep_analysis`. Each axis is swept independently, producing
+/// one simulated cell per combination (a full cross product).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepAxes {
+    /// Added/removed to every monster-mode `Creature::count` in the base encounters (e.g.
+    /// `[-1, 0, 1, 2]`). Clamped to never drop a count below zero.
+    pub monster_count_deltas: Vec<i32>,
+    /// Multiplies every monster-mode `Creature::hp` in the base encounters (e.g. `[0.8, 1.0, 1.2]`).
+    pub monster_hp_multipliers: Vec<f64>,
+    /// Number of players taken from the front of the base roster for each cell (e.g. `[3, 4, 5]`).
+    pub party_sizes: Vec<usize>,
+}
+
+/// One cell of `run_sweep_analysis`'s output matrix: the axis values that produced it, plus
+/// the resulting grade/tier/label/win-rate - everything a GM needs to spot the contiguous
+/// "good design" region without re-running the simulator by hand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepCell {
+    pub monster_count_delta: i32,
+    pub monster_hp_multiplier: f64,
+    pub party_size: usize,
+    pub safety_grade: SafetyGrade,
+    pub intensity_tier: IntensityTier,
+    pub encounter_label: EncounterLabel,
+    pub win_rate: f64,
+    pub is_good_design: bool,
+}
+
+/// Runs the simulation once per combination of `axes`' monster-count-delta, monster-HP-multiplier,
+/// and party-size values against `base_players`/`base_encounters`, analyzing each with
+/// `analyze_results` and collecting the grade/tier/label/win-rate into a flat matrix - one
+/// `SweepCell` per cell, in axis iteration order (party size outermost, then HP multiplier,
+/// then count delta), so a caller can reshape it into a 2-D/3-D table directly.
+pub fn run_sweep_analysis(
+    base_players: &[Creature],
+    base_encounters: &[Encounter],
+    scenario_name: &str,
+    axes: &SweepAxes,
+    iterations: usize,
+    seed: u64,
+) -> Vec<SweepCell> {
+    let mut cells = Vec::new();
+
+    for &party_size in &axes.party_sizes {
+        let players: Vec<Creature> = base_players.iter().take(party_size).cloned().collect();
+
+        for &monster_hp_multiplier in &axes.monster_hp_multipliers {
+            for &monster_count_delta in &axes.monster_count_deltas {
+                let encounters: Vec<Encounter> = base_encounters
+                    .iter()
+                    .map(|encounter| {
+                        let mut encounter = encounter.clone();
+                        for monster in &mut encounter.monsters {
+                            monster.hp = ((monster.hp as f64) * monster_hp_multiplier).round().max(1.0) as u32;
+                            monster.count = (monster.count + monster_count_delta as f64).max(0.0);
+                        }
+                        encounter
+                    })
+                    .collect();
+
+                let results = simulation::run_monte_carlo(&players, &encounters, iterations, seed);
+                let output = analyze_results(&results, scenario_name, party_size, &ScoreConfig::default());
+                let win_rate = output.global_median.as_ref().map(|d| d.win_rate).unwrap_or(0.0);
+
+                cells.push(SweepCell {
+                    monster_count_delta,
+                    monster_hp_multiplier,
+                    party_size,
+                    safety_grade: output.safety_grade,
+                    intensity_tier: output.intensity_tier,
+                    encounter_label: output.encounter_label,
+                    win_rate,
+                    is_good_design: output.is_good_design,
+                });
+            }
+        }
+    }
+
+    cells
+}
+
+fn safety_grade_ordinal(grade: &SafetyGrade) -> i32 {
+    match grade {
+        SafetyGrade::A => 0,
+        SafetyGrade::B => 1,
+        SafetyGrade::C => 2,
+        SafetyGrade::D => 3,
+        SafetyGrade::F => 4,
+    }
+}
+
+fn intensity_tier_ordinal(tier: &IntensityTier) -> i32 {
+    match tier {
+        IntensityTier::Tier1 => 0,
+        IntensityTier::Tier2 => 1,
+        IntensityTier::Tier3 => 2,
+        IntensityTier::Tier4 => 3,
+        IntensityTier::Tier5 => 4,
+    }
+}
+
+/// The `(SafetyGrade, IntensityTier)` ordinal pair that `get_encounter_label` maps to this
+/// label, if it names a specific combination - `Standard`/`TPKRisk`/`Broken` are catch-alls for
+/// several combinations and have no single target to search for.
+fn target_ordinals(label: &EncounterLabel) -> Option<(i32, i32)> {
+    let (grade, tier) = match label {
+        EncounterLabel::EpicChallenge => (SafetyGrade::B, IntensityTier::Tier4),
+        EncounterLabel::TacticalGrinder => (SafetyGrade::A, IntensityTier::Tier3),
+        EncounterLabel::ActionMovie => (SafetyGrade::B, IntensityTier::Tier2),
+        EncounterLabel::TheTrap => (SafetyGrade::C, IntensityTier::Tier2),
+        EncounterLabel::TheSlog => (SafetyGrade::A, IntensityTier::Tier5),
+        EncounterLabel::TrivialMinions => (SafetyGrade::A, IntensityTier::Tier1),
+        EncounterLabel::Standard | EncounterLabel::TPKRisk | EncounterLabel::Broken => return None,
+    };
+    Some((safety_grade_ordinal(&grade), intensity_tier_ordinal(&tier)))
+}
+
+fn loss_to_target(grade: &SafetyGrade, tier: &IntensityTier, target_grade_ord: i32, target_tier_ord: i32) -> f64 {
+    let dg = (safety_grade_ordinal(grade) - target_grade_ord) as f64;
+    let dt = (intensity_tier_ordinal(tier) - target_tier_ord) as f64;
+    dg * dg + dt * dt
+}
+
+/// Clones `base_encounters`, applying the three balance-search knobs to every monster: `hp`
+/// scaled by `monster_hp_multiplier`, `count` shifted by `monster_count_delta` (floored at
+/// zero), and every `Action::Atk` action's `dpr` scaled by `monster_damage_multiplier` via
+/// `dice::scale_dice_formula`.
+fn apply_balance_knobs(
+    base_encounters: &[Encounter],
+    monster_count_delta: f64,
+    monster_hp_multiplier: f64,
+    monster_damage_multiplier: f64,
+) -> Vec<Encounter> {
+    base_encounters
+        .iter()
+        .map(|encounter| {
+            let mut encounter = encounter.clone();
+            for monster in &mut encounter.monsters {
+                monster.hp = ((monster.hp as f64) * monster_hp_multiplier).round().max(1.0) as u32;
+                monster.count = (monster.count + monster_count_delta).max(0.0);
+                for action in &mut monster.actions {
+                    if let Action::Atk(atk) = action {
+                        atk.dpr = crate::dice::scale_dice_formula(&atk.dpr, monster_damage_multiplier);
+                    }
+                }
+            }
+            encounter
+        })
+        .collect()
+}
+
+fn evaluate_balance_knobs(
+    base_players: &[Creature],
+    base_encounters: &[Encounter],
+    scenario_name: &str,
+    party_size: usize,
+    iterations: usize,
+    seed: u64,
+    monster_count_delta: f64,
+    monster_hp_multiplier: f64,
+    monster_damage_multiplier: f64,
+) -> (SafetyGrade, IntensityTier) {
+    let encounters = apply_balance_knobs(base_encounters, monster_count_delta, monster_hp_multiplier, monster_damage_multiplier);
+    let results = simulation::run_monte_carlo(base_players, &encounters, iterations, seed);
+    let output = analyze_results(&results, scenario_name, party_size, &ScoreConfig::default());
+    (output.safety_grade, output.intensity_tier)
+}
+
+/// One step of `run_balance_search`'s coordinate-descent trace - the knob settings tried and
+/// the resulting grade/tier/loss, so a caller can see the whole exploration path, not just the
+/// final answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceSearchStep {
+    pub monster_count_delta: f64,
+    pub monster_hp_multiplier: f64,
+    pub monster_damage_multiplier: f64,
+    pub safety_grade: SafetyGrade,
+    pub intensity_tier: IntensityTier,
+    pub loss: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceSearchResult {
+    pub steps: Vec<BalanceSearchStep>,
+    pub final_monster_count_delta: f64,
+    pub final_monster_hp_multiplier: f64,
+    pub final_monster_damage_multiplier: f64,
+    pub converged: bool,
+}
+
+/// Coordinate-descent search for a `(monster_count_delta, monster_hp_multiplier,
+/// monster_damage_multiplier)` triple whose simulated `(SafetyGrade, IntensityTier)` matches
+/// `target_label`. Starting from the unmodified config, each iteration perturbs every knob up
+/// and down by `step_size`, re-runs a fresh (smaller, for speed) batch of `iterations` for each
+/// candidate, and keeps whichever perturbation reduces the squared-ordinal-distance loss the
+/// most; `step_size` halves whenever no perturbation improves on the current loss. Stops at
+/// zero loss, a vanishingly small step size, or `max_iterations`. Returns `None` if
+/// `target_label` is one of the catch-all labels with no single `(grade, tier)` to aim at.
+pub fn run_balance_search(
+    base_players: &[Creature],
+    base_encounters: &[Encounter],
+    scenario_name: &str,
+    party_size: usize,
+    target_label: &EncounterLabel,
+    iterations: usize,
+    seed: u64,
+    max_iterations: usize,
+) -> Option<BalanceSearchResult> {
+    let (target_grade_ord, target_tier_ord) = target_ordinals(target_label)?;
+
+    let mut monster_count_delta = 0.0_f64;
+    let mut monster_hp_multiplier = 1.0_f64;
+    let mut monster_damage_multiplier = 1.0_f64;
+    let mut step_size = 1.0_f64;
+
+    let (mut grade, mut tier) = evaluate_balance_knobs(
+        base_players, base_encounters, scenario_name, party_size, iterations, seed,
+        monster_count_delta, monster_hp_multiplier, monster_damage_multiplier,
+    );
+    let mut current_loss = loss_to_target(&grade, &tier, target_grade_ord, target_tier_ord);
+
+    let mut steps = vec![BalanceSearchStep {
+        monster_count_delta, monster_hp_multiplier, monster_damage_multiplier,
+        safety_grade: grade.clone(), intensity_tier: tier.clone(), loss: current_loss,
+    }];
+
+    let mut converged = current_loss <= 0.0;
+    let mut iteration = 0;
+
+    while !converged && iteration < max_iterations && step_size > 1e-3 {
+        iteration += 1;
+
+        let candidates = [
+            (monster_count_delta + step_size, monster_hp_multiplier, monster_damage_multiplier),
+            (monster_count_delta - step_size, monster_hp_multiplier, monster_damage_multiplier),
+            (monster_count_delta, monster_hp_multiplier + step_size * 0.1, monster_damage_multiplier),
+            (monster_count_delta, (monster_hp_multiplier - step_size * 0.1).max(0.05), monster_damage_multiplier),
+            (monster_count_delta, monster_hp_multiplier, monster_damage_multiplier + step_size * 0.1),
+            (monster_count_delta, monster_hp_multiplier, (monster_damage_multiplier - step_size * 0.1).max(0.05)),
+        ];
+
+        let mut best: Option<(f64, f64, f64, SafetyGrade, IntensityTier, f64)> = None;
+        for &(count_delta, hp_mult, dmg_mult) in &candidates {
+            let (g, t) = evaluate_balance_knobs(
+                base_players, base_encounters, scenario_name, party_size, iterations, seed,
+                count_delta, hp_mult, dmg_mult,
+            );
+            let l = loss_to_target(&g, &t, target_grade_ord, target_tier_ord);
+            if best.as_ref().map(|(.., best_loss)| l < *best_loss).unwrap_or(true) {
+                best = Some((count_delta, hp_mult, dmg_mult, g, t, l));
+            }
+        }
+
+        let (count_delta, hp_mult, dmg_mult, g, t, l) = best.unwrap();
+
+        if l < current_loss {
+            monster_count_delta = count_delta;
+            monster_hp_multiplier = hp_mult;
+            monster_damage_multiplier = dmg_mult;
+            grade = g;
+            tier = t;
+            current_loss = l;
+            steps.push(BalanceSearchStep {
+                monster_count_delta, monster_hp_multiplier, monster_damage_multiplier,
+                safety_grade: grade.clone(), intensity_tier: tier.clone(), loss: current_loss,
+            });
+            converged = current_loss <= 0.0;
+        } else {
+            step_size *= 0.5;
+        }
+    }
+
+    Some(BalanceSearchResult {
+        steps,
+        final_monster_count_delta: monster_count_delta,
+        final_monster_hp_multiplier: monster_hp_multiplier,
+        final_monster_damage_multiplier: monster_damage_multiplier,
+        converged,
+    })
+}
+
+/// Outcome of `run_adaptive_decile_analysis`: the usual `AggregateOutput`, plus how many runs
+/// it took to get there - mirroring how `boost_search::BoostSearchResult` reports its probe
+/// curve alongside the minimal-boost answer.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaptiveDecileOutput {
+    pub analysis: AggregateOutput,
+    /// Total number of `SimulationResult`s simulated across every increment.
+    pub total_simulations: usize,
+    /// Number of `batch_size`-run increments simulated before stopping.
+    pub increments: usize,
+    /// `true` if two consecutive increments moved the decile cut points by less than
+    /// `tolerance`; `false` if `max_simulations` was reached first without converging.
+    pub converged: bool,
+}
+
+/// The score at each of the 10 decile boundaries of `sorted_scores` (already sorted
+/// ascending). Returns all zeros for an empty slice.
+fn decile_cut_points(sorted_scores: &[f64]) -> [f64; 10] {
+    let mut cuts = [0.0; 10];
+    let n = sorted_scores.len();
+    if n == 0 {
+        return cuts;
+    }
+    for (i, cut) in cuts.iter_mut().enumerate() {
+        let idx = (((i + 1) as f64 / 10.0) * n as f64).floor() as usize;
+        *cut = sorted_scores[idx.min(n - 1)];
+    }
+    cuts
+}
+
+/// Simulate `players` vs. `encounters` in increments of `batch_size` runs, recomputing the 10
+/// decile score cut points after each increment, until two consecutive increments move every
+/// cut point by less than `tolerance` (relative to the score spread seen so far), or
+/// `max_simulations` total runs have been made - whichever comes first.
+///
+/// This gives cheap runs for lopsided encounters (the distribution stabilizes almost
+/// immediately) and spends more runs only where the outcome distribution is genuinely noisy,
+/// rather than callers guessing a single fixed iteration count up front.
+pub fn run_adaptive_decile_analysis(
+    players: &[Creature],
+    encounters: &[Encounter],
+    scenario_name: &str,
+    party_size: usize,
+    batch_size: usize,
+    max_simulations: usize,
+    tolerance: f64,
+    seed: u64,
+) -> AdaptiveDecileOutput {
+    let mut all_results: Vec<SimulationResult> = Vec::new();
+    let mut previous_cuts: Option<[f64; 10]> = None;
+    let mut stable_checks = 0u32;
+    let mut increments = 0usize;
+
+    loop {
+        increments += 1;
+        let batch = simulation::run_monte_carlo(
+            players,
+            encounters,
+            batch_size,
+            seed.wrapping_add(increments as u64),
+        );
+        all_results.extend(batch);
+
+        let mut sorted_scores: Vec<f64> = all_results
+            .iter()
+            .map(crate::aggregation::calculate_score)
+            .collect();
+        sorted_scores.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let cuts = decile_cut_points(&sorted_scores);
+
+        if let Some(prev) = previous_cuts {
+            let spread = (sorted_scores.last().copied().unwrap_or(0.0)
+                - sorted_scores.first().copied().unwrap_or(0.0))
+            .abs()
+            .max(1.0);
+            let max_move = cuts
+                .iter()
+                .zip(prev.iter())
+                .map(|(c, p)| (c - p).abs())
+                .fold(0.0, f64::max);
+
+            if max_move / spread < tolerance {
+                stable_checks += 1;
+                if stable_checks >= 2 {
+                    let analysis = run_decile_analysis(&all_results, scenario_name, party_size);
+                    return AdaptiveDecileOutput {
+                        analysis,
+                        total_simulations: all_results.len(),
+                        increments,
+                        converged: true,
+                    };
+                }
+            } else {
+                stable_checks = 0;
+            }
+        }
+        previous_cuts = Some(cuts);
+
+        if all_results.len() >= max_simulations {
+            let analysis = run_decile_analysis(&all_results, scenario_name, party_size);
+            return AdaptiveDecileOutput {
+                analysis,
+                total_simulations: all_results.len(),
+                increments,
+                converged: false,
+            };
+        }
+    }
 }
\ No newline at end of file
@@ -3,13 +3,26 @@ use crate::events::{RollResult, DieRoll};
 use rand::Rng;
 
 pub fn evaluate(formula: &DiceFormula, dice_multiplier: u32) -> f64 {
+    evaluate_with_rng(formula, dice_multiplier, &mut crate::rng::get_rng())
+}
+
+pub fn evaluate_detailed(formula: &DiceFormula, dice_multiplier: u32) -> RollResult {
+    evaluate_detailed_with_rng(formula, dice_multiplier, &mut crate::rng::get_rng())
+}
+
+/// Same as `evaluate`, but draws from `rng` instead of always reaching for the thread-local
+/// RNG - lets `ActionResolver` route a seeded combatant's damage/modifier rolls through that
+/// combatant's own `BattleRandom` sub-stream instead of the thread-local state, the same way
+/// `dice::expr::eval` already does.
+pub fn evaluate_with_rng(formula: &DiceFormula, dice_multiplier: u32, rng: &mut impl Rng) -> f64 {
     match formula {
         DiceFormula::Value(v) => *v,
-        DiceFormula::Expr(s) => parse_and_roll(s, dice_multiplier),
+        DiceFormula::Expr(s) => parse_and_roll(s, dice_multiplier, rng),
     }
 }
 
-pub fn evaluate_detailed(formula: &DiceFormula, dice_multiplier: u32) -> RollResult {
+/// Detailed counterpart to `evaluate_with_rng` - see that function for why `rng` is injected.
+pub fn evaluate_detailed_with_rng(formula: &DiceFormula, dice_multiplier: u32, rng: &mut impl Rng) -> RollResult {
     match formula {
         DiceFormula::Value(v) => RollResult {
             total: *v,
@@ -17,7 +30,7 @@ pub fn evaluate_detailed(formula: &DiceFormula, dice_multiplier: u32) -> RollRes
             modifiers: vec![("Base".to_string(), *v)],
             formula: v.to_string(),
         },
-        DiceFormula::Expr(s) => parse_and_roll_detailed(s, dice_multiplier),
+        DiceFormula::Expr(s) => parse_and_roll_detailed(s, dice_multiplier, rng),
     }
 }
 
@@ -28,6 +41,71 @@ pub fn average(formula: &DiceFormula) -> f64 {
     }
 }
 
+/// Scales a `DiceFormula`'s damage output by `factor` - used by balance-search tooling to
+/// probe a "monster damage multiplier" knob without touching `hp`/`ac`. `Value` formulas scale
+/// directly; `Expr` formulas scale each `+`/`-`-separated term's leading numeric coefficient
+/// (the dice count for `NdM` terms, the flat value otherwise), leaving bracket notation tags
+/// (e.g. `"2d6[Bless]"`) untouched - the same term shape `parse_average` already reads.
+pub fn scale_dice_formula(formula: &DiceFormula, factor: f64) -> DiceFormula {
+    match formula {
+        DiceFormula::Value(v) => DiceFormula::Value(v * factor),
+        DiceFormula::Expr(s) => DiceFormula::Expr(scale_expr(s, factor)),
+    }
+}
+
+fn scale_expr(expr: &str, factor: f64) -> String {
+    let s = expr.replace(' ', "");
+    let mut result = String::new();
+    let mut current_term = String::new();
+    let mut sign = 1.0;
+
+    for c in s.chars() {
+        if c == '+' || c == '-' {
+            append_scaled_term(&mut result, sign, &current_term, factor);
+            current_term.clear();
+            sign = if c == '+' { 1.0 } else { -1.0 };
+        } else {
+            current_term.push(c);
+        }
+    }
+    append_scaled_term(&mut result, sign, &current_term, factor);
+
+    result
+}
+
+fn append_scaled_term(result: &mut String, sign: f64, term: &str, factor: f64) {
+    if term.is_empty() {
+        return;
+    }
+    if !result.is_empty() {
+        result.push_str(if sign < 0.0 { "-" } else { "+" });
+    } else if sign < 0.0 {
+        result.push('-');
+    }
+    result.push_str(&scale_term(term, factor));
+}
+
+fn scale_term(term: &str, factor: f64) -> String {
+    let (cleaned_term, suffix) = match term.find('[') {
+        Some(bracket_pos) => (&term[..bracket_pos], &term[bracket_pos..]),
+        None => (term, ""),
+    };
+
+    if cleaned_term.contains('d') {
+        let parts: Vec<&str> = cleaned_term.splitn(2, 'd').collect();
+        if parts.len() == 2 {
+            let count = if parts[0].is_empty() { 1 } else { parts[0].parse::<i32>().unwrap_or(1) };
+            let scaled_count = ((count as f64) * factor).round().max(0.0) as i32;
+            return format!("{}d{}{}", scaled_count, parts[1], suffix);
+        }
+    }
+
+    match cleaned_term.parse::<f64>() {
+        Ok(value) => format!("{}{}", value * factor, suffix),
+        Err(_) => format!("{}{}", cleaned_term, suffix),
+    }
+}
+
 pub fn parse_average(expr: &str) -> f64 {
     // Similar to parse_and_roll but returns average
     let s = expr.replace(" ", "");
@@ -60,29 +138,325 @@ fn parse_term_average(term: &str) -> f64 {
         term
     };
 
-    if cleaned_term.contains('d') {
-        let parts: Vec<&str> = cleaned_term.split('d').collect();
-        if parts.len() == 2 {
-            let count = parts[0].parse::<i32>().unwrap_or(1);
-            let count = if count == 0 && parts[0].is_empty() {
-                1
+    if let Some(dice_term) = parse_dice_term(cleaned_term) {
+        if let Some((keep_highest, keep_n)) = dice_term.keep {
+            let dist = keep_pmf(dice_term.count, dice_term.sides, keep_highest, keep_n);
+            return dist.iter().map(|(v, p)| *v as f64 * p).sum();
+        }
+        if let Some(threshold) = dice_term.reroll {
+            return dice_term.count as f64 * reroll_once_average(dice_term.sides.max(1) as u32, threshold.max(0) as u32);
+        }
+        // Average of 1dN is (N+1)/2
+        return dice_term.count as f64 * (dice_term.sides as f64 + 1.0) / 2.0;
+    }
+    cleaned_term.parse::<f64>().unwrap_or(0.0)
+}
+
+/// Parsed shape of a dice term's `NdX`/`NdXkhK`/`NdXklK`/`NdXrT` portion, after any `[Tag]`
+/// suffix has already been stripped by the caller. `keep` is `Some((keep_highest, how_many))`
+/// for roll-and-keep terms like `2d20kh1` (advantage) or `2d20kl1` (disadvantage); `reroll` is
+/// `Some(threshold)` for reroll-once terms like `1d6r1` (any face `<= threshold` is rerolled
+/// once). A term has at most one of `keep`/`reroll`; `None`/`None` is a plain `NdX` term, which
+/// behaves exactly as it always has.
+struct DiceTerm {
+    count: i32,
+    sides: i32,
+    keep: Option<(bool, i32)>,
+    reroll: Option<i32>,
+}
+
+fn parse_dice_term(cleaned_term: &str) -> Option<DiceTerm> {
+    if !cleaned_term.contains('d') {
+        return None;
+    }
+
+    let (dice_part, keep, reroll) = if let Some(idx) = cleaned_term.find("kh") {
+        let keep_n = cleaned_term[idx + 2..].parse::<i32>().unwrap_or(1);
+        (&cleaned_term[..idx], Some((true, keep_n)), None)
+    } else if let Some(idx) = cleaned_term.find("kl") {
+        let keep_n = cleaned_term[idx + 2..].parse::<i32>().unwrap_or(1);
+        (&cleaned_term[..idx], Some((false, keep_n)), None)
+    } else if let Some(idx) = cleaned_term.find('r') {
+        let threshold = cleaned_term[idx + 1..].parse::<i32>().unwrap_or(1);
+        (&cleaned_term[..idx], None, Some(threshold))
+    } else {
+        (cleaned_term, None, None)
+    };
+
+    let parts: Vec<&str> = dice_part.splitn(2, 'd').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+    let count = if parts[0].is_empty() { 1 } else { parts[0].parse::<i32>().unwrap_or(1) };
+    let sides = parts[1].parse::<i32>().unwrap_or(6);
+    Some(DiceTerm { count, sides, keep, reroll })
+}
+
+/// Exact expected value of a single dN where any face `<= threshold` is rerolled once (the
+/// reroll always counts, for better or worse).
+fn reroll_once_average(sides: u32, threshold: u32) -> f64 {
+    let sides = sides as f64;
+    let threshold = (threshold as f64).min(sides);
+    let rerolled_faces_avg = threshold * (sides + 1.0) / 2.0;
+    let kept_faces_sum = sides * (sides + 1.0) / 2.0 - threshold * (threshold + 1.0) / 2.0;
+    (rerolled_faces_avg + kept_faces_sum) / sides
+}
+
+/// Exact PMF of a single dN where any face `<= threshold` is rerolled once: faces above
+/// `threshold` keep their usual `1/sides` mass, and the `threshold/sides` chance of triggering a
+/// reroll redistributes uniformly back across every face.
+fn reroll_die_pmf(sides: i64, threshold: i64) -> Vec<(i64, f64)> {
+    let sides = sides.max(1);
+    let threshold = threshold.clamp(0, sides);
+    let reroll_chance = threshold as f64 / sides as f64;
+    (1..=sides)
+        .map(|v| {
+            let base = if v > threshold { 1.0 / sides as f64 } else { 0.0 };
+            (v, base + reroll_chance / sides as f64)
+        })
+        .collect()
+}
+
+/// Above this many combined outcomes (`sides^count`), exact enumeration in `keep_pmf` would be
+/// too slow, so it falls back to the plain (no-keep) sum instead. Comfortably covers the
+/// documented use cases (`2d20kh1`/`2d20kl1` plus stacked bonus/penalty dice up to `4d20`).
+const MAX_KEEP_ENUMERATION_OUTCOMES: u128 = 200_000;
+
+/// Exact PMF of the sum of the best/worst `keep_n` out of `count` iid `1..=sides` dice,
+/// computed by enumerating every `sides^count` outcome (an odometer over `count` digits in
+/// base `sides`) and summing each outcome's kept dice. Falls back to the ordinary (no-keep)
+/// sum of all `count` dice - via repeated convolution, same as `term_pmf`'s plain-dice path -
+/// once that enumeration would exceed `MAX_KEEP_ENUMERATION_OUTCOMES`.
+fn keep_pmf(count: i32, sides: i32, keep_highest: bool, keep_n: i32) -> Vec<(i64, f64)> {
+    let count = count.max(0) as usize;
+    if count == 0 {
+        return vec![(0, 1.0)];
+    }
+    let sides = sides.max(1) as i64;
+    let keep_n = (keep_n.max(0) as usize).min(count);
+
+    let total_outcomes = (sides as u128).saturating_pow(count as u32);
+    if total_outcomes > MAX_KEEP_ENUMERATION_OUTCOMES {
+        let die: Vec<(i64, f64)> = (1..=sides).map(|v| (v, 1.0 / sides as f64)).collect();
+        let mut dist = vec![(0i64, 1.0)];
+        for _ in 0..count {
+            dist = convolve(&dist, &die);
+        }
+        return dist;
+    }
+
+    let prob_each = 1.0 / (sides as f64).powi(count as i32);
+    let mut totals: std::collections::HashMap<i64, f64> = std::collections::HashMap::new();
+    let mut combo = vec![1i64; count];
+    loop {
+        let mut sorted = combo.clone();
+        sorted.sort_unstable();
+        let kept_sum: i64 = if keep_highest {
+            sorted[count - keep_n..].iter().sum()
+        } else {
+            sorted[..keep_n].iter().sum()
+        };
+        *totals.entry(kept_sum).or_insert(0.0) += prob_each;
+
+        let mut idx = 0;
+        loop {
+            if idx == count {
+                let mut result: Vec<(i64, f64)> = totals.into_iter().collect();
+                result.sort_by_key(|(v, _)| *v);
+                return result;
+            }
+            combo[idx] += 1;
+            if combo[idx] > sides {
+                combo[idx] = 1;
+                idx += 1;
             } else {
-                count
+                break;
+            }
+        }
+    }
+}
+
+/// Whether an attack roll has advantage, disadvantage, or neither - mirrors the fold used by
+/// `expected_damage`/`hit_probability` below rather than `enums::AttackMode`, which tracks the
+/// power-attack tradeoff instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvantageState {
+    Normal,
+    Advantage,
+    Disadvantage,
+}
+
+/// Full probability mass function of a `DiceFormula`'s roll, computed analytically (no sampling)
+/// by convolving each die's uniform distribution: a single dN contributes uniform mass 1/N over
+/// 1..=N, independent terms combine via discrete convolution, and flat modifiers shift the
+/// support by a constant. Returned as (value, probability) pairs sorted by value.
+pub fn pmf(formula: &DiceFormula) -> Vec<(i64, f64)> {
+    match formula {
+        DiceFormula::Value(v) => vec![(v.round() as i64, 1.0)],
+        DiceFormula::Expr(s) => expr_pmf(s),
+    }
+}
+
+fn expr_pmf(expr: &str) -> Vec<(i64, f64)> {
+    let s = expr.replace(' ', "");
+    let mut terms: Vec<(f64, String)> = Vec::new();
+    let mut current_term = String::new();
+    let mut sign = 1.0;
+
+    for c in s.chars() {
+        if c == '+' || c == '-' {
+            if !current_term.is_empty() {
+                terms.push((sign, current_term.clone()));
+                current_term.clear();
+            }
+            sign = if c == '+' { 1.0 } else { -1.0 };
+        } else {
+            current_term.push(c);
+        }
+    }
+    if !current_term.is_empty() {
+        terms.push((sign, current_term));
+    }
+
+    let mut dist = vec![(0i64, 1.0)];
+    for (sign, term) in &terms {
+        dist = convolve(&dist, &term_pmf(term, *sign));
+    }
+    dist
+}
+
+fn term_pmf(term: &str, sign: f64) -> Vec<(i64, f64)> {
+    let cleaned_term = match term.find('[') {
+        Some(bracket_pos) => &term[..bracket_pos],
+        None => term,
+    };
+
+    if let Some(dice_term) = parse_dice_term(cleaned_term) {
+        if dice_term.count > 0 && dice_term.sides > 0 {
+            let dist = match dice_term.keep {
+                Some((keep_highest, keep_n)) => keep_pmf(dice_term.count, dice_term.sides, keep_highest, keep_n),
+                None => {
+                    let die: Vec<(i64, f64)> = match dice_term.reroll {
+                        Some(threshold) => reroll_die_pmf(dice_term.sides as i64, threshold as i64),
+                        None => (1..=dice_term.sides as i64).map(|v| (v, 1.0 / dice_term.sides as f64)).collect(),
+                    };
+                    let mut dist = vec![(0i64, 1.0)];
+                    for _ in 0..dice_term.count {
+                        dist = convolve(&dist, &die);
+                    }
+                    dist
+                }
             };
-            let sides = parts[1].parse::<i32>().unwrap_or(6);
+            return dist.into_iter().map(|(v, p)| (((v as f64) * sign) as i64, p)).collect();
+        }
+    }
 
-            // Average of 1dN is (N+1)/2
-            return count as f64 * (sides as f64 + 1.0) / 2.0;
+    let value = cleaned_term.parse::<f64>().unwrap_or(0.0);
+    vec![(((value * sign).round()) as i64, 1.0)]
+}
+
+fn convolve(a: &[(i64, f64)], b: &[(i64, f64)]) -> Vec<(i64, f64)> {
+    let mut out: std::collections::HashMap<i64, f64> = std::collections::HashMap::new();
+    for &(av, ap) in a {
+        for &(bv, bp) in b {
+            *out.entry(av + bv).or_insert(0.0) += ap * bp;
         }
     }
-    cleaned_term.parse::<f64>().unwrap_or(0.0)
+    let mut result: Vec<(i64, f64)> = out.into_iter().collect();
+    result.sort_by_key(|(v, _)| *v);
+    result
 }
 
-fn parse_and_roll(expr: &str, dice_multiplier: u32) -> f64 {
-    parse_and_roll_detailed(expr, dice_multiplier).total
+/// Mean contribution of only `formula`'s dice terms (excluding flat numeric modifiers) - used by
+/// `expected_damage` to implement "on a crit, the dice (not modifiers) roll twice." A pure
+/// `DiceFormula::Value` has no dice terms, so it contributes 0.
+fn dice_only_mean(formula: &DiceFormula) -> f64 {
+    match formula {
+        DiceFormula::Value(_) => 0.0,
+        DiceFormula::Expr(s) => parse_average(s) - flat_only_average(s),
+    }
 }
 
-fn parse_and_roll_detailed(expr: &str, dice_multiplier: u32) -> RollResult {
+fn flat_only_average(expr: &str) -> f64 {
+    let s = expr.replace(' ', "");
+    let mut sum = 0.0;
+    let mut current_term = String::new();
+    let mut sign = 1.0;
+
+    for c in s.chars() {
+        if c == '+' || c == '-' {
+            if !current_term.is_empty() {
+                sum += sign * flat_term_value(&current_term);
+                current_term.clear();
+            }
+            sign = if c == '+' { 1.0 } else { -1.0 };
+        } else {
+            current_term.push(c);
+        }
+    }
+    if !current_term.is_empty() {
+        sum += sign * flat_term_value(&current_term);
+    }
+    sum
+}
+
+fn flat_term_value(term: &str) -> f64 {
+    let cleaned_term = match term.find('[') {
+        Some(bracket_pos) => &term[..bracket_pos],
+        None => term,
+    };
+    if cleaned_term.contains('d') {
+        0.0
+    } else {
+        cleaned_term.parse::<f64>().unwrap_or(0.0)
+    }
+}
+
+/// Chance an attack with `to_hit_bonus` against `target_ac` connects, folded for
+/// advantage/disadvantage. `P(hit) = (21-(AC-bonus))/20` clamped to [0.05, 0.95] (nat 1 always
+/// misses, nat 20 always hits); advantage gives `1-(1-p)^2`, disadvantage gives `p^2`.
+pub fn hit_probability(to_hit_bonus: f64, target_ac: f64, advantage: AdvantageState) -> f64 {
+    let p = ((21.0 - (target_ac - to_hit_bonus)) / 20.0).clamp(0.05, 0.95);
+    match advantage {
+        AdvantageState::Normal => p,
+        AdvantageState::Advantage => 1.0 - (1.0 - p).powi(2),
+        AdvantageState::Disadvantage => p.powi(2),
+    }
+}
+
+/// Expected damage of a single attack, combining `formula`'s analytic PMF mean with
+/// `hit_probability` and the crit rule that a natural 20 rolls the dice (not modifiers) twice -
+/// crit damage is `mean + dice_only_mean`. The nat-20 chance is fixed at 1/20 of the *unfolded*
+/// d20 (reshaped the same way as `hit_probability` for advantage/disadvantage), then split out of
+/// the overall hit chance so a normal hit and a crit aren't double-counted.
+pub fn expected_damage(formula: &DiceFormula, to_hit_bonus: f64, target_ac: f64, advantage: AdvantageState) -> f64 {
+    let mean: f64 = pmf(formula).iter().map(|(v, p)| *v as f64 * p).sum();
+    let crit_damage = mean + dice_only_mean(formula);
+
+    let p_hit = hit_probability(to_hit_bonus, target_ac, advantage);
+    let p_crit = match advantage {
+        AdvantageState::Normal => 1.0 / 20.0,
+        AdvantageState::Advantage => 1.0 - (19.0_f64 / 20.0).powi(2),
+        AdvantageState::Disadvantage => (1.0_f64 / 20.0).powi(2),
+    };
+    let p_normal_hit = (p_hit - p_crit).max(0.0);
+
+    p_normal_hit * mean + p_crit * crit_damage
+}
+
+/// Chance a single hit drops a target currently at `current_hp` below `threshold` HP, read
+/// directly off `formula`'s analytic PMF rather than sampled - built for exact "does this kill
+/// them" queries.
+pub fn chance_to_drop_below(formula: &DiceFormula, current_hp: i64, threshold: i64) -> f64 {
+    let needed = current_hp - threshold + 1;
+    pmf(formula).into_iter().filter(|(v, _)| *v >= needed).map(|(_, p)| p).sum()
+}
+
+fn parse_and_roll(expr: &str, dice_multiplier: u32, rng: &mut impl Rng) -> f64 {
+    parse_and_roll_detailed(expr, dice_multiplier, rng).total
+}
+
+fn parse_and_roll_detailed(expr: &str, dice_multiplier: u32, rng: &mut impl Rng) -> RollResult {
     let s = expr.replace(" ", "");
     let mut total = 0.0;
     let mut rolls = Vec::new();
@@ -93,7 +467,7 @@ fn parse_and_roll_detailed(expr: &str, dice_multiplier: u32) -> RollResult {
     for c in s.chars() {
         if c == '+' || c == '-' {
             if !current_term.is_empty() {
-                let (val, term_rolls, term_mods) = parse_term_detailed(&current_term, dice_multiplier, sign);
+                let (val, term_rolls, term_mods) = parse_term_detailed(&current_term, dice_multiplier, sign, rng);
                 total += val;
                 rolls.extend(term_rolls);
                 modifiers.extend(term_mods);
@@ -105,7 +479,7 @@ fn parse_and_roll_detailed(expr: &str, dice_multiplier: u32) -> RollResult {
         }
     }
     if !current_term.is_empty() {
-        let (val, term_rolls, term_mods) = parse_term_detailed(&current_term, dice_multiplier, sign);
+        let (val, term_rolls, term_mods) = parse_term_detailed(&current_term, dice_multiplier, sign, rng);
         total += val;
         rolls.extend(term_rolls);
         modifiers.extend(term_mods);
@@ -119,7 +493,19 @@ fn parse_and_roll_detailed(expr: &str, dice_multiplier: u32) -> RollResult {
     }
 }
 
-fn parse_term_detailed(term: &str, dice_multiplier: u32, sign: f64) -> (f64, Vec<DieRoll>, Vec<(String, f64)>) {
+/// Roll a single `sides`-sided die, rerolling once if the first roll is `<= threshold` (and
+/// keeping whatever the reroll lands on, for better or worse).
+fn roll_one(sides: u32, reroll_threshold: Option<i32>, rng: &mut impl Rng) -> u32 {
+    let first = rng.gen_range(1..=sides);
+    if let Some(threshold) = reroll_threshold {
+        if (first as i32) <= threshold {
+            return rng.gen_range(1..=sides);
+        }
+    }
+    first
+}
+
+fn parse_term_detailed(term: &str, dice_multiplier: u32, sign: f64, rng: &mut impl Rng) -> (f64, Vec<DieRoll>, Vec<(String, f64)>) {
     let (cleaned_term, name) = if let Some(bracket_pos) = term.find('[') {
         let name = term[bracket_pos + 1..term.len() - 1].to_string();
         (&term[..bracket_pos], Some(name))
@@ -127,31 +513,37 @@ fn parse_term_detailed(term: &str, dice_multiplier: u32, sign: f64) -> (f64, Vec
         (term, None)
     };
 
-    if cleaned_term.contains('d') {
-        let parts: Vec<&str> = cleaned_term.split('d').collect();
-        if parts.len() == 2 {
-            let count = parts[0].parse::<i32>().unwrap_or(1);
-            let count = if count == 0 && parts[0].is_empty() { 1 } else { count };
-            let sides = parts[1].parse::<i32>().unwrap_or(6);
-
-            let mut rng = rand::thread_rng();
-            let mut term_total = 0.0;
-            let mut term_rolls = Vec::new();
-            for _ in 0..(count * dice_multiplier as i32) {
-                let val = rng.gen_range(1..=sides) as u32;
-                term_total += val as f64;
-                term_rolls.push(DieRoll { sides: sides as u32, value: val });
+    if let Some(dice_term) = parse_dice_term(cleaned_term) {
+        let sides = dice_term.sides.max(1);
+        let total_dice = (dice_term.count * dice_multiplier as i32).max(0);
+
+        let mut rolled: Vec<u32> = (0..total_dice).map(|_| roll_one(sides as u32, dice_term.reroll, rng)).collect();
+        // Logged in roll order (final, post-reroll value), before any sorting for
+        // keep-highest/lowest selection below.
+        let term_rolls: Vec<DieRoll> = rolled.iter().map(|&v| DieRoll { sides: sides as u32, value: v }).collect();
+
+        let term_total: f64 = match dice_term.keep {
+            Some((keep_highest, keep_n)) => {
+                rolled.sort_unstable();
+                let keep_n = (keep_n.max(0) as usize).min(rolled.len());
+                let kept: u32 = if keep_highest {
+                    rolled[rolled.len() - keep_n..].iter().sum()
+                } else {
+                    rolled[..keep_n].iter().sum()
+                };
+                kept as f64
             }
-            
-            let val = sign * term_total;
-            let mut modifiers = Vec::new();
-            // ALWAYS add to modifiers, even if no bracket name exists
-            // Use the roll result as the value, and the term string as the name if missing
-            let modifier_name = name.unwrap_or_else(|| cleaned_term.to_string());
-            modifiers.push((modifier_name, val));
-            
-            return (val, term_rolls, modifiers);
-        }
+            None => rolled.iter().sum::<u32>() as f64,
+        };
+
+        let val = sign * term_total;
+        let mut modifiers = Vec::new();
+        // ALWAYS add to modifiers, even if no bracket name exists
+        // Use the roll result as the value, and the term string as the name if missing
+        let modifier_name = name.unwrap_or_else(|| cleaned_term.to_string());
+        modifiers.push((modifier_name, val));
+
+        return (val, term_rolls, modifiers);
     }
 
     let val = sign * cleaned_term.parse::<f64>().unwrap_or(0.0);
@@ -162,6 +554,72 @@ fn parse_term_detailed(term: &str, dice_multiplier: u32, sign: f64) -> (f64, Vec
     (val, Vec::new(), modifiers)
 }
 
+/// Thin wrappers around the `NdX`/`NdXkhK`/`NdXklK`/`NdXrT` parser above (`parse_dice_term` and
+/// friends), for call sites that want a generic-`Rng` sampler or a static average rather than
+/// going through a `DiceFormula`. Used to be its own separate tokenizer/evaluator with a
+/// duplicate keep-highest/lowest implementation; that duplication is gone now that both paths
+/// share one parser, so reroll support (added for this module) and bracket-tag modifiers
+/// (already supported by the module-level parser) are both available everywhere.
+pub mod expr {
+    use rand::Rng;
+
+    /// Sample a total for `expr`, drawing rolls from `rng` - works with both the thread-local
+    /// `crate::rng::ThreadLocalRng` and per-combatant `crate::rng::BattleRandom` sub-streams,
+    /// since both just implement `rand::Rng`.
+    pub fn eval(expr: &str, rng: &mut impl Rng) -> i32 {
+        super::parse_and_roll(expr, 1, rng).round() as i32
+    }
+
+    /// Expected value of `expr`, for static projections that have no RNG to sample from. Exact,
+    /// except that a keep-N term too large to enumerate (see `keep_pmf`) counts as the plain sum
+    /// of all its dice.
+    pub fn average(expr: &str) -> f64 {
+        super::parse_average(expr)
+    }
+
+    /// Static integer projection of `average`, for call sites (the Shield Wall survivability
+    /// estimate) that want a single representative bonus rather than a distribution.
+    pub fn flat_bonus(expr: &str) -> i32 {
+        average(expr).round() as i32
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_flat_bonus_simple() {
+            assert_eq!(flat_bonus("5"), 5);
+            assert_eq!(flat_bonus("1d6+3"), 7);
+        }
+
+        #[test]
+        fn test_average_multi_term() {
+            assert_eq!(average("2d6+1d4+3"), 7.0 + 2.5 + 3.0);
+        }
+
+        #[test]
+        fn test_average_advantage_disadvantage() {
+            assert!(average("2d20kh1") > 10.5);
+            assert!(average("2d20kl1") < 10.5);
+        }
+
+        #[test]
+        fn test_average_reroll_once() {
+            assert!(average("1d6r1") > 3.5);
+        }
+
+        #[test]
+        fn test_eval_advantage_stays_in_range() {
+            use rand::SeedableRng;
+            let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+            for _ in 0..50 {
+                assert!((1..=20).contains(&eval("2d20kh1", &mut rng)));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,35 +627,35 @@ mod tests {
     #[test]
     fn test_dice_parsing() {
         // Since it's random, we can't assert exact values easily, but we can check ranges or run multiple times
-        let res = parse_and_roll("1d1+5", 1);
+        let res = parse_and_roll("1d1+5", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 6.0);
 
-        let res = parse_and_roll("10", 1);
+        let res = parse_and_roll("10", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 10.0);
     }
 
     #[test]
     fn test_bracket_notation() {
         // Test simple bracket notation
-        let res = parse_and_roll("3[PB]+5[STR]", 1);
+        let res = parse_and_roll("3[PB]+5[STR]", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 8.0);
 
-        let res = parse_and_roll("10[Base]-5[SS]", 1);
+        let res = parse_and_roll("10[Base]-5[SS]", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 5.0);
 
         // Test single bracketed value
-        let res = parse_and_roll("7[Modifier]", 1);
+        let res = parse_and_roll("7[Modifier]", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 7.0);
     }
 
     #[test]
     fn test_dice_with_brackets() {
         // Test dice notation with brackets
-        let res = parse_and_roll("1d1[Bless]+3[Guidance]", 1);
+        let res = parse_and_roll("1d1[Bless]+3[Guidance]", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 4.0);
 
         // Test complex formula
-        let res = parse_and_roll("3[PB]+5[STR]+2[Weapon]-5[SS]", 1);
+        let res = parse_and_roll("3[PB]+5[STR]+2[Weapon]-5[SS]", 1, &mut crate::rng::get_rng());
         assert_eq!(res, 5.0);
     }
 
@@ -210,4 +668,119 @@ mod tests {
         let res = parse_average("1d4[Bless]+2[Guidance]");
         assert_eq!(res, 4.5); // Average of 1d4 is 2.5, plus 2 = 4.5
     }
+
+    #[test]
+    fn test_scale_dice_formula_value() {
+        let scaled = scale_dice_formula(&DiceFormula::Value(10.0), 1.5);
+        assert_eq!(scaled, DiceFormula::Value(15.0));
+    }
+
+    #[test]
+    fn test_scale_dice_formula_expr() {
+        let scaled = scale_dice_formula(&DiceFormula::Expr("2d6+3".to_string()), 2.0);
+        assert_eq!(average(&scaled), 2.0 * average(&DiceFormula::Expr("2d6+3".to_string())));
+
+        let scaled_brackets = scale_dice_formula(&DiceFormula::Expr("1d4[Bless]-2[Guidance]".to_string()), 2.0);
+        assert_eq!(parse_average(match &scaled_brackets { DiceFormula::Expr(s) => s, _ => unreachable!() }), 2.0 * 2.5 - 4.0);
+    }
+
+    #[test]
+    fn test_pmf_single_die_sums_to_one() {
+        let dist = pmf(&DiceFormula::Expr("1d6".to_string()));
+        assert_eq!(dist, vec![(1, 1.0 / 6.0), (2, 1.0 / 6.0), (3, 1.0 / 6.0), (4, 1.0 / 6.0), (5, 1.0 / 6.0), (6, 1.0 / 6.0)]);
+        let total: f64 = dist.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pmf_matches_average() {
+        let formula = DiceFormula::Expr("2d6+3".to_string());
+        let dist = pmf(&formula);
+        let mean: f64 = dist.iter().map(|(v, p)| *v as f64 * p).sum();
+        assert!((mean - average(&formula)).abs() < 1e-9);
+        let total: f64 = dist.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hit_probability_clamped_and_folded() {
+        assert_eq!(hit_probability(0.0, 100.0, AdvantageState::Normal), 0.05);
+        assert_eq!(hit_probability(100.0, 0.0, AdvantageState::Normal), 0.95);
+        let p = hit_probability(5.0, 15.0, AdvantageState::Normal);
+        let adv = hit_probability(5.0, 15.0, AdvantageState::Advantage);
+        let dis = hit_probability(5.0, 15.0, AdvantageState::Disadvantage);
+        assert!((adv - (1.0 - (1.0 - p).powi(2))).abs() < 1e-9);
+        assert!((dis - p.powi(2)).abs() < 1e-9);
+        assert!(adv > p && p > dis);
+    }
+
+    #[test]
+    fn test_expected_damage_includes_crit_dice_doubling() {
+        let formula = DiceFormula::Expr("1d6+3".to_string());
+        let dmg = expected_damage(&formula, 5.0, 15.0, AdvantageState::Normal);
+        assert!(dmg > 0.0);
+
+        // With the hit chance at its 95% cap (a natural 1 always misses), crits add exactly one
+        // extra die-mean on top of the normal hits.
+        let always_hits = expected_damage(&formula, 1000.0, 0.0, AdvantageState::Normal);
+        let p_crit = 1.0 / 20.0;
+        let expected = (0.95 - p_crit) * average(&formula) + p_crit * (average(&formula) + 3.5);
+        assert!((always_hits - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_chance_to_drop_below() {
+        let formula = DiceFormula::Expr("1d6".to_string());
+        // Can't possibly drop a target at 10 HP below 3 with a 1d6 hit.
+        assert_eq!(chance_to_drop_below(&formula, 10, 3), 0.0);
+        // Rolling a 6 drops a target at 6 HP to 0, which is below 1.
+        assert!((chance_to_drop_below(&formula, 6, 1) - 1.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keep_highest_average_beats_flat_average() {
+        // Advantage on a d20: keeping the best of two rolls should pull the average above a
+        // single die's 10.5, and keeping the worst should pull it below.
+        assert!(average(&DiceFormula::Expr("2d20kh1".to_string())) > 10.5);
+        assert!(average(&DiceFormula::Expr("2d20kl1".to_string())) < 10.5);
+    }
+
+    #[test]
+    fn test_keep_highest_pmf_matches_brute_force() {
+        // 2d6kh1: P(max == 6) should be the classic 11/36 (at least one of the two dice shows a 6).
+        let dist = pmf(&DiceFormula::Expr("2d6kh1".to_string()));
+        let p_six = dist.iter().find(|(v, _)| *v == 6).map(|(_, p)| *p).unwrap_or(0.0);
+        assert!((p_six - 11.0 / 36.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_keep_roll_stays_in_range() {
+        // Rolling "3d20kh2" (two extra bonus dice, keep the best two of three) should never
+        // total less than 2 or more than 40.
+        let formula = DiceFormula::Expr("3d20kh2".to_string());
+        for _ in 0..100 {
+            let total = evaluate(&formula, 1);
+            assert!((2.0..=40.0).contains(&total));
+        }
+    }
+
+    #[test]
+    fn test_reroll_once_reaches_dice_formula_through_the_shared_parser() {
+        // "1d6r1" (reroll a 1 once) pulls the average above a plain 1d6's 3.5, stays in
+        // [1, 6], and its PMF integrates to 1 - exercised via `DiceFormula` (not `dice::expr`
+        // directly) to confirm `evaluate`/`average`/`pmf` all understand the same reroll suffix.
+        let formula = DiceFormula::Expr("1d6r1".to_string());
+        assert!(average(&formula) > 3.5);
+
+        for _ in 0..100 {
+            let total = evaluate(&formula, 1);
+            assert!((1.0..=6.0).contains(&total));
+        }
+
+        let dist = pmf(&formula);
+        let total_p: f64 = dist.iter().map(|(_, p)| p).sum();
+        assert!((total_p - 1.0).abs() < 1e-9);
+        let mean: f64 = dist.iter().map(|(v, p)| *v as f64 * p).sum();
+        assert!((mean - average(&formula)).abs() < 1e-9);
+    }
 }
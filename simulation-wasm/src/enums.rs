@@ -27,6 +27,14 @@ pub enum EnemyTarget {
     EnemyWithLowestAC,
     #[serde(rename = "enemy with highest AC")]
     EnemyWithHighestAC,
+    /// "Smart" targeting: each attack picks whichever living enemy it would deal the most
+    /// damage to after that enemy's damage-type modifier (weakness/resistance/immunity).
+    /// In `targeting::get_targets` the estimate also counts hit chance, and each team's picks
+    /// are claimed for the round, so fire spreads until every living enemy is claimed.
+    /// `ActionResolver` ranks by average damage and the modifier alone and keeps no claims,
+    /// so there several attacks can pick the same enemy while others are left untargeted.
+    #[serde(rename = "focus fire")]
+    FocusFire,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -148,6 +156,62 @@ pub enum BuffDuration {
     EntireEncounter,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DamageType {
+    Acid,
+    Bludgeoning,
+    Cold,
+    Fire,
+    Force,
+    Lightning,
+    Necrotic,
+    Piercing,
+    Poison,
+    Psychic,
+    Radiant,
+    Slashing,
+    Thunder,
+    Radiation,
+}
+
+/// Per-combattant action-selection strategy, so scripted tactics can be benchmarked
+/// against near-optimal play. In `execute_turn`, `Aggressive`/`Defensive` resolve to a
+/// `crate::strategy::CombatStrategy` and `Mcts` to `crate::planner`; `Scripted` runs
+/// `execute_turn`'s inline selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AiMode {
+    /// Fixed `action_slot` scripts chosen via `get_actions`/`get_targets` (the default);
+    /// `crate::strategy::GreedyPriority` mirrors this logic as a `CombatStrategy`.
+    Scripted,
+    /// Always takes the action(s) maximizing this turn's expected damage; gated behind the
+    /// `aggressive_ai` feature. `crate::strategy::Aggressive`.
+    Aggressive,
+    /// Prioritizes self-buffs/healing while below an HP threshold, otherwise falls back to
+    /// `crate::strategy::GreedyPriority`; gated behind the `defensive_ai` feature. `crate::strategy::Defensive`.
+    Defensive,
+    /// Monte Carlo Tree Search over this turn's candidate actions (see `crate::planner`).
+    Mcts,
+}
+
+impl Default for AiMode {
+    fn default() -> Self {
+        AiMode::Scripted
+    }
+}
+
+/// Which accuracy/damage tradeoff an attack roll was resolved with - "Power" mirrors Great
+/// Weapon Master/Sharpshooter (-5 to hit, +10 damage). Chosen per-attack by
+/// `action_resolver::should_power_attack` rather than stored on the action itself, so the same
+/// `AtkAction` can flex between modes as the target's effective AC changes turn to turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AttackMode {
+    Normal,
+    Power,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TriggerCondition {
@@ -165,4 +229,61 @@ pub enum TriggerCondition {
     OnEnemyDeath, // e.g. Great Weapon Master, Dark One's Blessing
     #[serde(rename = "on critical hit")]
     OnCriticalHit, // e.g. Divine Smite (crit fishing)
+    /// Fires when this trigger's owner drops to 0 HP - see `Event::CreatureDied` and
+    /// `cleanup::apply_on_death_triggers`. Typically paired with `TriggerEffect::AreaEffect` to
+    /// model "explodes on death" monsters (Sickening Radiance clouds, self-destructs).
+    #[serde(rename = "on death")]
+    OnDeath,
+    /// Fires when at least `count` combatants are in `Reaction::Hostile` to this trigger's owner
+    /// (per `factions::FactionTable`), rather than a raw "enemy team" headcount - see
+    /// `factions::FactionTable::count_with_reaction`. Evaluating this requires the full roster,
+    /// which `reactions::event_matches_trigger` doesn't currently receive.
+    #[serde(rename = "enemy count at least")]
+    EnemyCountAtLeast { count: usize },
+    /// Runs a cached Rune script against the triggering `Event` instead of a closed-set Rust
+    /// variant - see `rune_scripting`. With the `rune` cargo feature the script is compiled and
+    /// cached but not executed, so the condition never fires, with or without the feature.
+    #[serde(rename = "script")]
+    Script { source: String },
+}
+
+/// What a `TriggerCondition`-matched `EffectTrigger` does - either a built-in, closed-set effect
+/// or (with the `rune` cargo feature) an arbitrary script. See `rune_scripting` for how `Script`
+/// is compiled and cached; the script itself is not executed.
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TriggerEffect {
+    DealDamage { amount: String, damage_type: String },
+    /// Fires on `TriggerCondition::OnDeath`: every combatant "in radius" (no positional model
+    /// exists yet, so this applies to every other living combatant in the encounter - see
+    /// `cleanup::apply_on_death_triggers`) rolls a save against `dc`; on a failed save it takes
+    /// `damage` (if any) and picks up `buff` as a rider, e.g. a Sickening Radiance cloud or a
+    /// self-destructing monster's death burst.
+    AreaEffect {
+        dc: crate::model::DiceFormula,
+        radius: f64,
+        buff: crate::model::Buff,
+        damage: Option<crate::model::DiceFormula>,
+        /// Only combatants in this `Reaction` to the trigger's owner are affected - e.g.
+        /// `Some(Reaction::Hostile)` for a death burst that spares allies. `None` affects every
+        /// other combatant regardless of faction, preserving the original behavior.
+        #[serde(default)]
+        reaction_filter: Option<crate::factions::Reaction>,
+    },
+    /// Script whose `rune_scripting::ScriptMutation`s the engine applies - see
+    /// `rune_scripting::run_effect_script`, which compiles the script but does not execute it,
+    /// so the list of mutations is always empty.
+    Script { source: String },
+}
+
+/// Gates a `TriggerEffect` on something beyond the triggering event itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TriggerRequirement {
+    HasTempHP,
+    /// Gates the effect on the target's `factions::Reaction` to the trigger's owner - e.g.
+    /// `Friendly` so an aura only buffs allies instead of every combatant a `TriggerEffect`
+    /// would otherwise reach. `ActionResolver`'s requirement check does not evaluate it: the
+    /// requirement is treated as met for every target.
+    TargetReaction { reaction: crate::factions::Reaction },
 }
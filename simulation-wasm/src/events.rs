@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use crate::enums::{CreatureCondition, TriggerCondition};
+use crate::enums::{AttackMode, CreatureCondition, TriggerCondition};
 use crate::model::Action;
 
 /// Comprehensive event enum covering all combat interactions
@@ -8,8 +8,8 @@ use crate::model::Action;
 pub enum Event {
     // Combat Events
     ActionStarted { actor_id: String, action_id: String },
-    AttackHit { attacker_id: String, target_id: String, damage: f64 },
-    AttackMissed { attacker_id: String, target_id: String },
+    AttackHit { attacker_id: String, target_id: String, damage: f64, mode: AttackMode },
+    AttackMissed { attacker_id: String, target_id: String, mode: AttackMode },
     DamageTaken { target_id: String, damage: f64, damage_type: String },
     DamagePrevented { target_id: String, prevented_amount: f64 },
 
@@ -34,6 +34,11 @@ pub enum Event {
 
     // Life Cycle Events
     UnitDied { unit_id: String, killer_id: Option<String>, damage_type: Option<String> },
+    /// Returned by `cleanup::apply_on_death_triggers` after a dying creature's
+    /// `TriggerCondition::OnDeath` area effects have been applied (not returned for a creature
+    /// that has none) - distinct from `UnitDied`, which this codebase's
+    /// resolution pipeline doesn't currently emit.
+    CreatureDied { creature_id: String },
     TurnStarted { unit_id: String, round_number: u32 },
     TurnEnded { unit_id: String, round_number: u32 },
     RoundStarted { round_number: u32 },
@@ -414,6 +419,7 @@ mod tests {
             attacker_id: "attacker".to_string(),
             target_id: "target".to_string(),
             damage: 10.0,
+            mode: AttackMode::Normal,
         };
 
         assert_eq!(event.get_source_id(), Some("attacker".to_string()));
@@ -432,6 +438,7 @@ mod tests {
             attacker_id: "attacker".to_string(),
             target_id: "target".to_string(),
             damage: 10.0,
+            mode: AttackMode::Normal,
         };
 
         assert_eq!(event.get_type(), "AttackHit");
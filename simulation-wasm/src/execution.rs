@@ -21,6 +21,10 @@ pub struct ActionExecutionEngine {
 
     /// Resolves actions into events
     action_resolver: ActionResolver,
+
+    /// Root seed this engine's `action_resolver` was built with, if any, stamped onto every
+    /// `TurnResult`/`EncounterResult` it produces so the encounter can be re-run bit-for-bit.
+    root_seed: Option<u64>,
 }
 
 /// Result of executing a single action
@@ -53,6 +57,10 @@ pub struct TurnResult {
     pub effects_applied: Vec<String>, // Effect IDs applied during this turn
     pub start_hp: u32,
     pub end_hp: u32,
+    /// Root RNG seed the encounter was built with, for exact reproduction (0 if the engine
+    /// used the legacy thread-local RNG fallback instead of an explicit seed).
+    #[serde(default)]
+    pub seed: u64,
 }
 
 /// Result of a complete encounter
@@ -65,6 +73,10 @@ pub struct EncounterResult {
     pub round_snapshots: Vec<Vec<CombattantState>>, // Snapshots of all combatants at end of each round
     pub event_history: Vec<Event>,
     pub statistics: EncounterStatistics,
+    /// Root RNG seed the encounter was built with, for exact reproduction (0 if the engine
+    /// used the legacy thread-local RNG fallback instead of an explicit seed).
+    #[serde(default)]
+    pub seed: u64,
 }
 
 /// Statistics collected during an encounter
@@ -77,11 +89,33 @@ pub struct EncounterStatistics {
     pub reactions_triggered: u32,
     pub critical_hits: u32,
     pub total_actions_executed: u32,
+    /// Attack rolls resolved in `AttackMode::Power` (GWM/Sharpshooter-style), hit or miss.
+    pub power_attacks_used: u32,
+    /// Times a combatant lost concentration (one per `ConcentrationBroken` event, which
+    /// `TurnContext::break_concentration` emits after a failed concentration save).
+    pub concentration_breaks: u32,
 }
 
 impl ActionExecutionEngine {
-    /// Create a new execution engine for the given combatants
+    /// Create a new execution engine for the given combatants. Rolls are drawn from the
+    /// legacy thread-local RNG (see `crate::rng`); use `new_with_seed` for a reproducible encounter.
     pub fn new(combatants: Vec<Combattant>, log_enabled: bool) -> Self {
+        Self::new_inner(combatants, log_enabled, ActionResolver::new(), None)
+    }
+
+    /// Create a new execution engine whose rolls are drawn from `ActionResolver::with_seed`
+    /// instead of the thread-local RNG, so the resulting `TurnResult`/`EncounterResult`s can be
+    /// re-run bit-for-bit from `seed` alone.
+    pub fn new_with_seed(combatants: Vec<Combattant>, log_enabled: bool, seed: u64) -> Self {
+        Self::new_inner(combatants, log_enabled, ActionResolver::with_seed(seed), Some(seed))
+    }
+
+    fn new_inner(
+        combatants: Vec<Combattant>,
+        log_enabled: bool,
+        action_resolver: ActionResolver,
+        root_seed: Option<u64>,
+    ) -> Self {
         // Initialize TurnContext with empty battlefield conditions
         let context = TurnContext::new(
             combatants.clone(),
@@ -94,7 +128,8 @@ impl ActionExecutionEngine {
         let mut engine = Self {
             context,
             reaction_manager: ReactionManager::new(),
-            action_resolver: ActionResolver::new(),
+            action_resolver,
+            root_seed,
         };
 
         // Register reactions from combatants (placeholder for now)
@@ -429,6 +464,7 @@ impl ActionExecutionEngine {
             effects_applied,
             start_hp,
             end_hp,
+            seed: self.root_seed.unwrap_or(0),
         }
     }
 
@@ -947,6 +983,7 @@ impl ActionExecutionEngine {
             round_snapshots,
             event_history,
             statistics,
+            seed: self.root_seed.unwrap_or(0),
         }
     }
 
@@ -992,16 +1029,22 @@ impl ActionExecutionEngine {
         let mut reactions_triggered = 0u32;
         let mut critical_hits = 0u32;
         let mut total_actions_executed = 0u32;
+        let mut power_attacks_used = 0u32;
+        let mut concentration_breaks = 0u32;
 
         for event in events {
             match event {
                 Event::AttackHit {
                     attacker_id,
                     damage,
+                    mode,
                     ..
                 } => {
                     *total_damage_dealt.entry(attacker_id.clone()).or_insert(0.0) += damage;
                     *attacks_landed.entry(attacker_id.clone()).or_insert(0) += 1;
+                    if *mode == crate::enums::AttackMode::Power {
+                        power_attacks_used += 1;
+                    }
 
                     // Check if it was a critical hit (simplified check)
                     // In a real implementation, this would be determined by the attack
@@ -1010,8 +1053,11 @@ impl ActionExecutionEngine {
                         critical_hits += 1;
                     }
                 }
-                Event::AttackMissed { attacker_id, .. } => {
+                Event::AttackMissed { attacker_id, mode, .. } => {
                     *attacks_missed.entry(attacker_id.clone()).or_insert(0) += 1;
+                    if *mode == crate::enums::AttackMode::Power {
+                        power_attacks_used += 1;
+                    }
                 }
                 Event::HealingApplied {
                     source_id, amount, ..
@@ -1026,6 +1072,9 @@ impl ActionExecutionEngine {
                         reactions_triggered += 1;
                     }
                 }
+                Event::ConcentrationBroken { .. } => {
+                    concentration_breaks += 1;
+                }
                 _ => {}
             }
         }
@@ -1038,6 +1087,8 @@ impl ActionExecutionEngine {
             reactions_triggered,
             critical_hits,
             total_actions_executed,
+            power_attacks_used,
+            concentration_breaks,
         }
     }
 
@@ -0,0 +1,136 @@
+//! Faction relationships beyond the binary `Combattant.team` model. `TriggerEffect::AreaEffect`'s
+//! `reaction_filter` resolves "is this an enemy/ally?" through a `FactionTable` instead of a
+//! hardcoded team index, so three-or-more-sided fights and temporary reaction flips (charm, turn)
+//! work without special-casing team 0/1. `EnemyCountAtLeast` and
+//! `TriggerRequirement::TargetReaction` are not evaluated against a `FactionTable`:
+//! `reactions::event_matches_trigger` returns `false` for the former, and `ActionResolver`
+//! treats the latter as always met.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How one faction regards another (or itself). Mirrors the repo's other small closed-set enums
+/// (e.g. `CreatureCondition`) rather than a numeric affinity score, since reactions are discrete
+/// and usually only need the three D&D-standard buckets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Reaction {
+    Hostile,
+    Neutral,
+    Friendly,
+}
+
+/// Maps each combatant to a faction id and each pair of factions to a `Reaction`, loadable as
+/// data (e.g. alongside the encounter/scenario JSON). Combatants with no entry fall back to the
+/// `Combattant.team` binary (team 0 is Hostile to team 1 and vice versa, Friendly to itself) so
+/// existing two-team scenarios work without authoring a `FactionTable` at all.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FactionTable {
+    /// Combatant id -> faction id.
+    #[serde(default)]
+    pub members: HashMap<String, String>,
+    /// faction id -> (other faction id -> Reaction). Only one direction needs to be present;
+    /// lookups check both orderings. A faction's reaction to itself defaults to Friendly if
+    /// absent. Nested maps (rather than a tuple-keyed map) so this round-trips through JSON.
+    #[serde(default)]
+    pub reactions: HashMap<String, HashMap<String, Reaction>>,
+}
+
+impl FactionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn faction_of(&self, combatant_id: &str, fallback_team: u32) -> String {
+        self.members
+            .get(combatant_id)
+            .cloned()
+            .unwrap_or_else(|| format!("team{}", fallback_team))
+    }
+
+    /// How `observer_id` (on `observer_team`) regards `target_id` (on `target_team`). Falls back
+    /// to the binary team model for any combatant not present in `members`.
+    pub fn reaction_between(
+        &self,
+        observer_id: &str,
+        observer_team: u32,
+        target_id: &str,
+        target_team: u32,
+    ) -> Reaction {
+        let observer_faction = self.faction_of(observer_id, observer_team);
+        let target_faction = self.faction_of(target_id, target_team);
+
+        if observer_faction == target_faction {
+            return Reaction::Friendly;
+        }
+
+        if let Some(reaction) = self.reactions.get(&observer_faction).and_then(|m| m.get(&target_faction)) {
+            return *reaction;
+        }
+        if let Some(reaction) = self.reactions.get(&target_faction).and_then(|m| m.get(&observer_faction)) {
+            return *reaction;
+        }
+
+        // No faction data at all: preserve the plain two-team behavior.
+        if observer_team == target_team {
+            Reaction::Friendly
+        } else {
+            Reaction::Hostile
+        }
+    }
+
+    /// Counts how many of `candidates` (id, team) are in `reaction` to `owner_id`/`owner_team`,
+    /// excluding `owner_id` itself - the building block `EnemyCountAtLeast` needs once combat
+    /// state (the full roster) is threaded into trigger evaluation.
+    pub fn count_with_reaction(
+        &self,
+        owner_id: &str,
+        owner_team: u32,
+        candidates: &[(&str, u32)],
+        reaction: Reaction,
+    ) -> usize {
+        candidates
+            .iter()
+            .filter(|(id, _)| *id != owner_id)
+            .filter(|(id, team)| self.reaction_between(owner_id, owner_team, id, *team) == reaction)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_team_fallback_with_no_faction_data() {
+        let table = FactionTable::new();
+        assert_eq!(table.reaction_between("a", 0, "b", 1), Reaction::Hostile);
+        assert_eq!(table.reaction_between("a", 0, "c", 0), Reaction::Friendly);
+    }
+
+    #[test]
+    fn test_three_faction_table_overrides_team_binary() {
+        let mut table = FactionTable::new();
+        table.members.insert("a".to_string(), "redcaps".to_string());
+        table.members.insert("b".to_string(), "bluecaps".to_string());
+        table.members.insert("c".to_string(), "greencaps".to_string());
+        table.reactions.entry("redcaps".to_string()).or_default().insert("bluecaps".to_string(), Reaction::Hostile);
+        table.reactions.entry("redcaps".to_string()).or_default().insert("greencaps".to_string(), Reaction::Neutral);
+
+        assert_eq!(table.reaction_between("a", 0, "b", 1), Reaction::Hostile);
+        assert_eq!(table.reaction_between("a", 0, "c", 1), Reaction::Neutral);
+    }
+
+    #[test]
+    fn test_count_with_reaction_excludes_owner() {
+        let mut table = FactionTable::new();
+        table.members.insert("a".to_string(), "party".to_string());
+        table.members.insert("b".to_string(), "party".to_string());
+        table.members.insert("c".to_string(), "monsters".to_string());
+        table.reactions.entry("party".to_string()).or_default().insert("monsters".to_string(), Reaction::Hostile);
+
+        let candidates = vec![("a", 0), ("b", 0), ("c", 1)];
+        assert_eq!(table.count_with_reaction("a", 0, &candidates, Reaction::Hostile), 1);
+        assert_eq!(table.count_with_reaction("a", 0, &candidates, Reaction::Friendly), 1);
+    }
+}
@@ -0,0 +1,256 @@
+// Genetic-algorithm encounter balancer - tunes a single encounter's monster stats
+// (HP, AC, count, to-hit, damage) to hit a target win rate and median score, rather than
+// `auto_balancer`'s directed per-role nudging or `boost_search`'s single-scalar bisection.
+// A population of candidate encounters evolves under tournament selection, per-field
+// crossover/mutation, and elitism, using `simulation::run_monte_carlo` for fitness exactly
+// like `boost_search::probe_win_rate` does for its probes.
+use crate::dice;
+use crate::model::*;
+use crate::simulation;
+use rand::prelude::*;
+
+const POPULATION_SIZE: usize = 30;
+const MAX_GENERATIONS: usize = 40;
+const TOURNAMENT_SIZE: usize = 3;
+const MUTATION_RATE: f64 = 0.1;
+/// Stop early once fitness (win-rate error plus weighted median-score error) drops below this.
+const FITNESS_THRESHOLD: f64 = 0.02;
+/// Weight applied to the median-score term so it's commensurate with a win-rate error in `[0, 1]`.
+const MEDIAN_SCORE_PENALTY: f64 = 0.01;
+/// Fraction of a stat's baseline value a single mutation can shift it by.
+const MUTATION_SPAN: f64 = 0.25;
+
+/// Win rate and median score a balanced encounter should achieve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BalanceTarget {
+    pub win_rate: f64,
+    pub median_score: f64,
+}
+
+/// Outcome of `balance_encounter_genetic`.
+#[derive(Debug, Clone)]
+pub struct GeneticBalanceResult {
+    pub best_encounter: Encounter,
+    pub achieved_win_rate: f64,
+    pub achieved_median_score: f64,
+    pub fitness: f64,
+    pub generations_run: usize,
+}
+
+/// The mutable numeric fields of one monster, collapsed to plain `f64` so crossover and
+/// mutation can treat every monster in the encounter the same way regardless of how its
+/// `to_hit`/`dpr` dice formulas were originally expressed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MonsterGenes {
+    hp: f64,
+    ac: f64,
+    count: f64,
+    to_hit: f64,
+    dpr: f64,
+}
+
+type Genome = Vec<MonsterGenes>;
+
+fn genes_from_monster(monster: &Creature) -> MonsterGenes {
+    let first_atk = monster.actions.iter().find_map(|action| match action {
+        Action::Atk(atk) => Some(atk),
+        _ => None,
+    });
+    MonsterGenes {
+        hp: monster.hp as f64,
+        ac: monster.ac as f64,
+        count: monster.count,
+        to_hit: first_atk.map(|atk| dice::average(&atk.to_hit)).unwrap_or(0.0),
+        dpr: first_atk.map(|atk| dice::average(&atk.dpr)).unwrap_or(0.0),
+    }
+}
+
+/// Apply `genes` to a copy of `monster`, leaving the original untouched - same shape as
+/// `boost_search::boosted_creature`. Every `Atk` action's `to_hit`/`dpr` collapses to the
+/// genome's flat `DiceFormula::Value`, the same simplification `boosted_creature` makes.
+fn monster_from_genes(monster: &Creature, genes: &MonsterGenes) -> Creature {
+    let mut monster = monster.clone();
+    monster.hp = genes.hp.round().max(1.0) as u32;
+    monster.ac = genes.ac.round().max(1.0) as u32;
+    monster.count = genes.count.round().max(1.0);
+    for action in &mut monster.actions {
+        if let Action::Atk(atk) = action {
+            atk.to_hit = DiceFormula::Value(genes.to_hit);
+            atk.dpr = DiceFormula::Value(genes.dpr.max(0.0));
+        }
+    }
+    monster
+}
+
+fn genome_from_encounter(baseline: &Encounter) -> Genome {
+    baseline.monsters.iter().map(genes_from_monster).collect()
+}
+
+fn encounter_from_genome(baseline: &Encounter, genome: &Genome) -> Encounter {
+    let mut encounter = baseline.clone();
+    for (monster, genes) in encounter.monsters.iter_mut().zip(genome) {
+        *monster = monster_from_genes(monster, genes);
+    }
+    encounter
+}
+
+/// Perturb every field of every monster's genes by up to `span` of its baseline value, used to
+/// seed the initial population; offspring are mutated separately, per field, by `mutate`.
+fn perturb(genome: &Genome, span: f64, rng: &mut StdRng) -> Genome {
+    genome
+        .iter()
+        .map(|genes| MonsterGenes {
+            hp: genes.hp * (1.0 + rng.gen_range(-span..=span)),
+            ac: genes.ac * (1.0 + rng.gen_range(-span..=span)),
+            count: genes.count * (1.0 + rng.gen_range(-span..=span)),
+            to_hit: genes.to_hit + genes.to_hit.abs().max(1.0) * rng.gen_range(-span..=span),
+            dpr: (genes.dpr * (1.0 + rng.gen_range(-span..=span))).max(0.0),
+        })
+        .collect()
+}
+
+/// Per-field uniform-swap crossover: each monster's each gene independently comes from
+/// `a` or `b` with equal probability.
+fn crossover(a: &Genome, b: &Genome, rng: &mut StdRng) -> Genome {
+    a.iter()
+        .zip(b)
+        .map(|(ga, gb)| MonsterGenes {
+            hp: if rng.gen_bool(0.5) { ga.hp } else { gb.hp },
+            ac: if rng.gen_bool(0.5) { ga.ac } else { gb.ac },
+            count: if rng.gen_bool(0.5) { ga.count } else { gb.count },
+            to_hit: if rng.gen_bool(0.5) { ga.to_hit } else { gb.to_hit },
+            dpr: if rng.gen_bool(0.5) { ga.dpr } else { gb.dpr },
+        })
+        .collect()
+}
+
+/// Each field independently has a `MUTATION_RATE` chance of a fresh perturbation.
+fn mutate(genome: &mut Genome, baseline: &Genome, rng: &mut StdRng) {
+    for (genes, baseline_genes) in genome.iter_mut().zip(baseline) {
+        if rng.gen_bool(MUTATION_RATE) {
+            genes.hp = baseline_genes.hp * (1.0 + rng.gen_range(-MUTATION_SPAN..=MUTATION_SPAN));
+        }
+        if rng.gen_bool(MUTATION_RATE) {
+            genes.ac = baseline_genes.ac * (1.0 + rng.gen_range(-MUTATION_SPAN..=MUTATION_SPAN));
+        }
+        if rng.gen_bool(MUTATION_RATE) {
+            genes.count = (baseline_genes.count * (1.0 + rng.gen_range(-MUTATION_SPAN..=MUTATION_SPAN))).max(1.0);
+        }
+        if rng.gen_bool(MUTATION_RATE) {
+            genes.to_hit = baseline_genes.to_hit
+                + baseline_genes.to_hit.abs().max(1.0) * rng.gen_range(-MUTATION_SPAN..=MUTATION_SPAN);
+        }
+        if rng.gen_bool(MUTATION_RATE) {
+            genes.dpr =
+                (baseline_genes.dpr * (1.0 + rng.gen_range(-MUTATION_SPAN..=MUTATION_SPAN))).max(0.0);
+        }
+    }
+}
+
+/// Runs `iterations` Monte Carlo trials of `genome` applied to `baseline` and scores how far
+/// it is from `target`. Reuses `simulation::run_monte_carlo`, so every candidate gets the same
+/// parallel/seeded engine `boost_search` and `auto_balancer` already build on.
+fn evaluate(
+    genome: &Genome,
+    baseline: &Encounter,
+    players: &[Creature],
+    target: BalanceTarget,
+    iterations: usize,
+    seed: u64,
+) -> (f64, Encounter, f64, f64) {
+    let encounter = encounter_from_genome(baseline, genome);
+    let results = simulation::run_monte_carlo(players, std::slice::from_ref(&encounter), iterations, seed);
+
+    if results.is_empty() {
+        return (f64::INFINITY, encounter, 0.0, 0.0);
+    }
+
+    let win_rate = results.iter().filter(|result| simulation::run_is_win(result)).count() as f64 / results.len() as f64;
+
+    let mut scores: Vec<f64> = results.iter().filter_map(|result| result.score).collect();
+    scores.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median_score = scores.get(scores.len() / 2).copied().unwrap_or(0.0);
+
+    let fitness = (win_rate - target.win_rate).abs() + MEDIAN_SCORE_PENALTY * (median_score - target.median_score).abs();
+    (fitness, encounter, win_rate, median_score)
+}
+
+fn tournament_select<'a>(
+    population: &'a [Genome],
+    fitnesses: &[f64],
+    rng: &mut StdRng,
+) -> &'a Genome {
+    let mut best_idx = rng.gen_range(0..population.len());
+    for _ in 1..TOURNAMENT_SIZE {
+        let idx = rng.gen_range(0..population.len());
+        if fitnesses[idx] < fitnesses[best_idx] {
+            best_idx = idx;
+        }
+    }
+    &population[best_idx]
+}
+
+/// Evolves `baseline`'s monster stats toward `target`'s win rate and median score.
+///
+/// Each generation evaluates the whole population against `iterations_per_probe` Monte
+/// Carlo trials (all candidates in a generation share a seed so their fitnesses are
+/// directly comparable), keeps the fittest genome unchanged (elitism), and fills the rest
+/// of the next generation from tournament-selected (k=3) parents combined by per-field
+/// crossover and mutated per-field at `MUTATION_RATE`. Stops after `MAX_GENERATIONS` or once
+/// the best fitness falls below `FITNESS_THRESHOLD`, whichever comes first.
+pub fn balance_encounter_genetic(
+    players: &[Creature],
+    baseline: &Encounter,
+    target: BalanceTarget,
+    iterations_per_probe: usize,
+    seed: u64,
+) -> GeneticBalanceResult {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let baseline_genome = genome_from_encounter(baseline);
+
+    let mut population: Vec<Genome> = (0..POPULATION_SIZE)
+        .map(|i| if i == 0 { baseline_genome.clone() } else { perturb(&baseline_genome, MUTATION_SPAN, &mut rng) })
+        .collect();
+
+    let mut best_result = evaluate(&baseline_genome, baseline, players, target, iterations_per_probe, seed);
+    let mut generations_run = 0;
+
+    for generation in 0..MAX_GENERATIONS {
+        generations_run = generation + 1;
+        let generation_seed = seed.wrapping_add(generation as u64);
+
+        let evaluated: Vec<(f64, Encounter, f64, f64)> = population
+            .iter()
+            .map(|genome| evaluate(genome, baseline, players, target, iterations_per_probe, generation_seed))
+            .collect();
+        let fitnesses: Vec<f64> = evaluated.iter().map(|(fitness, ..)| *fitness).collect();
+
+        let (best_idx, _) = fitnesses
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .expect("population is never empty");
+
+        if evaluated[best_idx].0 < best_result.0 {
+            best_result = evaluated[best_idx].clone();
+        }
+
+        if best_result.0 <= FITNESS_THRESHOLD {
+            break;
+        }
+
+        let elite = population[best_idx].clone();
+        let mut next_population = vec![elite];
+        while next_population.len() < POPULATION_SIZE {
+            let parent_a = tournament_select(&population, &fitnesses, &mut rng);
+            let parent_b = tournament_select(&population, &fitnesses, &mut rng);
+            let mut child = crossover(parent_a, parent_b, &mut rng);
+            mutate(&mut child, &baseline_genome, &mut rng);
+            next_population.push(child);
+        }
+        population = next_population;
+    }
+
+    let (fitness, best_encounter, achieved_win_rate, achieved_median_score) = best_result;
+    GeneticBalanceResult { best_encounter, achieved_win_rate, achieved_median_score, fitness, generations_run }
+}
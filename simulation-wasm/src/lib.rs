@@ -1,9 +1,11 @@
+pub mod api; // Seed-taking simulation entry points (runner); dto/wasm stay undeclared, see api/mod.rs
 pub mod dice;
 pub mod rng;
 pub mod actions;
 pub mod targeting;
 pub mod enums;
 pub mod model;
+pub mod factions; // Faction/reaction table for N-sided encounters and trigger targeting
 pub mod aggregation;
 pub mod cleanup;
 pub mod resolution;
@@ -11,6 +13,7 @@ pub mod resources;
 pub mod events;
 pub mod context;
 pub mod reactions;
+pub mod rune_scripting; // Optional Rune scripting layer for Script trigger conditions/effects
 pub mod execution;
 pub mod action_resolver;
 pub mod validation; // New module for requirement validation
@@ -23,6 +26,7 @@ pub mod adjustment_test;
 pub mod auto_balancer;
 pub mod dice_reconstruction;
 pub mod intensity_calculation;
+pub mod optimizer; // Resource-loadout optimizer maximizing expected damage per EHP budget
 #[cfg(test)]
 mod intensity_test;
 pub mod error_handling; // Enhanced error handling system
@@ -39,17 +43,28 @@ pub mod user_interaction; // User interaction flows
 pub mod config; // Configuration system
 pub mod storage; // Stub storage module
 pub mod storage_manager; // Stub storage manager module
+pub mod storage_integration; // Retry/backoff orchestration atop the queue and progress systems
 pub mod cache;
 pub mod log_reproduction_test;
 pub mod utils; // Utility functions for simulation results
 pub mod seed_selection; // Seed selection algorithms for Two-Pass
 pub mod simulation; // Core simulation execution functions
+pub mod planner; // MCTS-based alternative to scripted action-slot selection
+pub mod colorize; // ANSI-colored combat log rendering for the CLI
+pub mod boost_search; // Binary-search minimum player boost for a target win rate
+pub mod genetic_balancer; // GA-based encounter balancer tuning monster stats to a target win rate
+pub mod party_rating; // Glicko-1 round-robin ranking of candidate party compositions
+pub mod benchmark; // Batch seed-range benchmark runner emitting a JSON/CSV/Markdown comparison table
+pub mod strategy; // Pluggable per-combatant CombatStrategy implementations
+pub mod concentration; // Data-driven concentration-template registry
 pub mod two_pass; // Two-Pass deterministic re-simulation system
 pub mod memory_guardrails; // Memory safety protections for large simulations
+pub mod streaming_stats; // Online (O(1) memory) aggregation for simulation batches
+pub mod scratch; // Reusable per-iteration buffers to cut allocator churn in hot simulation loops
 pub mod wasm_api; // WASM bindings and JavaScript interface
 
 // Re-export commonly used functions for external access
-pub use simulation::{run_single_event_driven_simulation, run_single_lightweight_simulation, run_survey_pass};
+pub use api::runner::{run_single_event_driven_simulation, run_single_lightweight_simulation, run_survey_pass};
 pub use seed_selection::select_interesting_seeds_with_tiers;
 pub use two_pass::{run_simulation_with_rolling_stats, run_simulation_with_three_tier};
 
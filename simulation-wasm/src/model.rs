@@ -493,6 +493,11 @@ pub struct ActionTrigger {
 pub enum CleanupInstruction {
     RemoveAllBuffsFromSource(String), // Combatant ID of the source that died
     BreakConcentration(String, String), // (Combatant ID of concentrator, Buff ID)
+    /// Combatant ID of a creature that just dropped to 0 HP - runs its `TriggerCondition::OnDeath`
+    /// buff triggers (see `cleanup::apply_on_death_triggers`). Pushed before
+    /// `RemoveAllBuffsFromSource` for the same ID so the dying creature's own buffs are still
+    /// present when its death triggers are evaluated.
+    TriggerOnDeath(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -581,6 +586,16 @@ pub struct Creature {
     pub hit_dice: Option<String>, // Changed from DiceFormula
     #[serde(rename = "conModifier")]
     pub con_modifier: Option<f64>, // New field for constitution modifier to apply to hit dice rolls
+
+    /// Action-selection strategy for this creature's turns - see `crate::enums::AiMode`.
+    #[serde(default, rename = "aiMode")]
+    pub ai_mode: crate::enums::AiMode,
+    /// Rollout budget for `AiMode::Mcts` (see `crate::planner::choose_action_mcts`). `None`
+    /// uses `planner::DEFAULT_ITERATIONS`; `Some(0)` disables the search entirely so this
+    /// creature's turns fall back to the scripted path even while `ai_mode` is `Mcts`,
+    /// keeping a scenario deterministic without having to flip `ai_mode` back.
+    #[serde(default, rename = "mctsIterations")]
+    pub mcts_iterations: Option<usize>,
 }
 
 impl Hash for Creature {
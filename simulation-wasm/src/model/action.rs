@@ -100,6 +100,10 @@ pub struct AtkAction {
     pub half_on_save: Option<bool>,
     #[serde(rename = "riderEffect")]
     pub rider_effect: Option<RiderEffect>,
+    // Damage type driving resistance/vulnerability/immunity lookups on the target.
+    // Defaults to None (untyped damage) so existing encounters keep working unmodified.
+    #[serde(rename = "damageType", default)]
+    pub damage_type: Option<crate::enums::DamageType>,
 }
 
 impl AtkAction {
@@ -102,6 +102,19 @@ pub struct Creature {
     #[serde(default)]
     #[serde(rename = "initialBuffs")]
     pub initial_buffs: Vec<Buff>, // Buffs from magic items applied at encounter start
+
+    // Damage-type defensive profile. Order of precedence when a type appears in
+    // more than one list: immunity > weakness > resistance.
+    #[serde(default)]
+    pub immunities: Vec<crate::enums::DamageType>,
+    #[serde(default)]
+    pub weaknesses: Vec<crate::enums::DamageType>,
+    #[serde(default)]
+    pub resistances: Vec<crate::enums::DamageType>,
+
+    // Action-selection strategy for this combattant's turns (scripted slots vs. MCTS search).
+    #[serde(default, rename = "aiMode")]
+    pub ai_mode: crate::enums::AiMode,
 }
 
 fn default_initiative_bonus() -> DiceFormula {
@@ -154,6 +167,10 @@ impl Hash for Creature {
         self.magic_items.hash(state);
         self.max_arcane_ward_hp.hash(state);
         self.initial_buffs.hash(state);
+        self.immunities.hash(state);
+        self.weaknesses.hash(state);
+        self.resistances.hash(state);
+        self.ai_mode.hash(state);
     }
 }
 
@@ -298,6 +315,25 @@ impl Creature {
         (self.hp as f64 / hit_chance * rage_multiplier).round()
     }
 
+    /// Resolve the damage-type multiplier this creature applies to incoming damage of
+    /// `damage_type`, along with a short label for the combat log (e.g. "immune").
+    /// Untyped damage (`None`) is never modified. Precedence: immunity > weakness > resistance.
+    pub fn damage_type_modifier(&self, damage_type: Option<crate::enums::DamageType>) -> (f64, Option<&'static str>) {
+        let Some(damage_type) = damage_type else {
+            return (1.0, None);
+        };
+
+        if self.immunities.contains(&damage_type) {
+            (0.0, Some("immune"))
+        } else if self.weaknesses.contains(&damage_type) {
+            (2.0, Some("vulnerable ×2"))
+        } else if self.resistances.contains(&damage_type) {
+            (0.5, Some("resisted ×0.5"))
+        } else {
+            (1.0, None)
+        }
+    }
+
     /// Check if this creature is a Barbarian (has Rage class resource)
     fn is_barbarian(&self) -> bool {
         // Check class_resources for Rage
@@ -0,0 +1,184 @@
+//! Resource-loadout optimizer: given candidate actions priced in the same EHP currency as
+//! `intensity_calculation::calculate_ehp_points`/`calculate_power`, selects the mix that
+//! maximizes expected damage (typically from `dice::expected_damage`) subject to a fixed EHP
+//! budget, answering "what's my highest-damage nova that still leaves me a rest buffer?"
+
+use crate::resources::ResetType;
+
+/// One candidate action in a loadout search - its EHP cost (priced the same way
+/// `calculate_ehp_points` prices HP/spell slots/hit dice/class resources) and its expected
+/// damage, plus the `ResetType` that refills the resource it spends (if any).
+#[derive(Debug, Clone)]
+pub struct CandidateAction {
+    pub name: String,
+    pub ehp_cost: f64,
+    pub expected_damage: f64,
+    pub reset_type: Option<ResetType>,
+}
+
+/// A hard floor the optimizer must not violate - `min_remaining_ehp` keeps at least that much of
+/// the budget unspent, modeling constraints like "end with `calculate_power` >= 30%" borrowed
+/// from stat-optimizer tooling (expressed here in EHP terms rather than the 0-100 percent scale,
+/// since that's the currency this optimizer's DP already works in).
+#[derive(Debug, Clone, Default)]
+pub struct LoadoutConstraint {
+    pub min_remaining_ehp: Option<f64>,
+}
+
+/// The action subset chosen for one round, and what it cost/dealt.
+#[derive(Debug, Clone)]
+pub struct OptimizedLoadout {
+    pub chosen: Vec<String>,
+    pub total_expected_damage: f64,
+    pub ehp_spent: f64,
+}
+
+/// Single-round nova optimizer: selects the subset of `candidates` that maximizes total expected
+/// damage without exceeding `ehp_budget`, via 0/1 knapsack DP over an EHP axis discretized to
+/// `resolution` units (`dp[i][c] = max(dp[i-1][c], dp[i-1][c-cost_i] + dmg_i)`). `constraint`
+/// prunes any solution that would leave less than `min_remaining_ehp` of the budget unspent.
+pub fn optimize_single_round(
+    candidates: &[CandidateAction],
+    ehp_budget: f64,
+    resolution: f64,
+    constraint: &LoadoutConstraint,
+) -> OptimizedLoadout {
+    let capacity = (ehp_budget / resolution).round().max(0.0) as usize;
+    let n = candidates.len();
+    let cost_units: Vec<usize> = candidates
+        .iter()
+        .map(|a| (a.ehp_cost / resolution).round().max(0.0) as usize)
+        .collect();
+
+    let mut dp = vec![vec![0.0_f64; capacity + 1]; n + 1];
+    for i in 1..=n {
+        for c in 0..=capacity {
+            dp[i][c] = dp[i - 1][c];
+            if cost_units[i - 1] <= c {
+                let with_action = dp[i - 1][c - cost_units[i - 1]] + candidates[i - 1].expected_damage;
+                if with_action > dp[i][c] {
+                    dp[i][c] = with_action;
+                }
+            }
+        }
+    }
+
+    let min_remaining_units = constraint
+        .min_remaining_ehp
+        .map(|v| (v / resolution).round().max(0.0) as usize)
+        .unwrap_or(0);
+    let max_spend = capacity.saturating_sub(min_remaining_units);
+
+    let best_c = (0..=max_spend)
+        .max_by(|&a, &b| dp[n][a].partial_cmp(&dp[n][b]).unwrap_or(std::cmp::Ordering::Equal))
+        .unwrap_or(0);
+
+    let mut chosen = Vec::new();
+    let mut c = best_c;
+    let mut spent_units = 0;
+    for i in (1..=n).rev() {
+        if dp[i][c] != dp[i - 1][c] {
+            chosen.push(candidates[i - 1].name.clone());
+            c -= cost_units[i - 1];
+            spent_units += cost_units[i - 1];
+        }
+    }
+    chosen.reverse();
+
+    OptimizedLoadout {
+        total_expected_damage: dp[n][best_c],
+        ehp_spent: spent_units as f64 * resolution,
+        chosen,
+    }
+}
+
+/// Multi-round extension of `optimize_single_round`: runs the knapsack independently each round
+/// against a running EHP budget, but refunds into that budget the cost of any chosen action whose
+/// `reset_type` resets at or before `replenish` - e.g. with `replenish =
+/// ResetType::ShortRest`, short-rest class features refill between encounters even though the
+/// EHP spent on long-rest resources (spell slots) carries forward across the whole horizon.
+pub fn optimize_nova(
+    candidates: &[CandidateAction],
+    ehp_budget: f64,
+    rounds: usize,
+    replenish: &ResetType,
+    resolution: f64,
+    constraint: &LoadoutConstraint,
+) -> Vec<OptimizedLoadout> {
+    let mut remaining_budget = ehp_budget;
+    let mut results = Vec::with_capacity(rounds);
+
+    for _ in 0..rounds {
+        let round_result = optimize_single_round(candidates, remaining_budget, resolution, constraint);
+
+        let refund: f64 = round_result
+            .chosen
+            .iter()
+            .filter_map(|name| candidates.iter().find(|a| &a.name == name))
+            .filter(|a| resets_before_or_at(&a.reset_type, replenish))
+            .map(|a| a.ehp_cost)
+            .sum();
+
+        remaining_budget = (remaining_budget - round_result.ehp_spent + refund).max(0.0);
+        results.push(round_result);
+    }
+
+    results
+}
+
+fn resets_before_or_at(reset: &Option<ResetType>, boundary: &ResetType) -> bool {
+    match reset {
+        None => false,
+        Some(r) => reset_rank(r) <= reset_rank(boundary),
+    }
+}
+
+fn reset_rank(reset_type: &ResetType) -> u8 {
+    match reset_type {
+        ResetType::Turn => 0,
+        ResetType::Round => 1,
+        ResetType::Encounter => 2,
+        ResetType::ShortRest => 3,
+        ResetType::LongRest => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(name: &str, ehp_cost: f64, expected_damage: f64) -> CandidateAction {
+        CandidateAction { name: name.to_string(), ehp_cost, expected_damage, reset_type: None }
+    }
+
+    #[test]
+    fn test_optimize_single_round_picks_best_affordable_mix() {
+        let candidates = vec![action("smite1", 15.0, 20.0), action("smite2", 15.0, 20.0), action("cantrip", 0.0, 5.0)];
+        let result = optimize_single_round(&candidates, 20.0, 1.0, &LoadoutConstraint::default());
+        assert_eq!(result.ehp_spent, 15.0);
+        assert_eq!(result.total_expected_damage, 25.0);
+        assert!(result.chosen.contains(&"cantrip".to_string()));
+    }
+
+    #[test]
+    fn test_optimize_single_round_respects_min_remaining_ehp() {
+        let candidates = vec![action("nova", 20.0, 100.0)];
+        let constraint = LoadoutConstraint { min_remaining_ehp: Some(5.0) };
+        let result = optimize_single_round(&candidates, 20.0, 1.0, &constraint);
+        assert!(result.ehp_spent <= 15.0);
+        assert!(result.chosen.is_empty());
+    }
+
+    #[test]
+    fn test_optimize_nova_refunds_short_rest_resources_between_rounds() {
+        let candidates = vec![CandidateAction {
+            name: "second_wind".to_string(),
+            ehp_cost: 10.0,
+            expected_damage: 10.0,
+            reset_type: Some(ResetType::ShortRest),
+        }];
+        let results = optimize_nova(&candidates, 10.0, 2, &ResetType::ShortRest, 1.0, &LoadoutConstraint::default());
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.chosen == vec!["second_wind".to_string()]));
+    }
+}
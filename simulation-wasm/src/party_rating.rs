@@ -0,0 +1,163 @@
+// Elo/Glicko rating of candidate party compositions via a round-robin tournament.
+//
+// Each timeline in the fixed set is treated as one Glicko-1 rating period (Glickman 1999):
+// every candidate party gets a deterministic Monte Carlo survey batch on that timeline (same
+// seed across parties, so differences in outcome reflect the build rather than which seeds got
+// drawn), and every ordered pair of parties is compared - a round-robin within the period -
+// using win rate (falling back to median score on a tie) as the match outcome. Reuses
+// `simulation::run_monte_carlo`/`run_is_win`, the same active batch-simulation entry point
+// `boost_search` and `genetic_balancer` build on.
+use crate::model::{Creature, Encounter};
+use crate::simulation;
+use std::f64::consts::PI;
+
+const INITIAL_RATING: f64 = 1500.0;
+const INITIAL_RD: f64 = 350.0;
+/// Glicko never lets a rating deviation grow past its starting value.
+const MAX_RD: f64 = 350.0;
+
+/// One candidate build entering the tournament.
+#[derive(Debug, Clone)]
+pub struct PartyCandidate {
+    pub name: String,
+    pub players: Vec<Creature>,
+}
+
+/// Final standing for one party after every timeline's rating period has been applied.
+#[derive(Debug, Clone)]
+pub struct LeaderboardEntry {
+    pub name: String,
+    pub rating: f64,
+    pub rating_deviation: f64,
+    /// Average combat win rate across every timeline surveyed, not the round-robin match
+    /// record (which only ever exists implicitly through the Glicko updates below).
+    pub win_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PartyPerformance {
+    win_rate: f64,
+    median_score: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RatingState {
+    rating: f64,
+    rd: f64,
+    win_rate_sum: f64,
+    periods: f64,
+}
+
+fn glicko_q() -> f64 {
+    10f64.ln() / 400.0
+}
+
+/// Glicko's `g(RD)` de-weighting function - an opponent with a large rating deviation
+/// contributes less certain information, so its influence on `E` is damped toward 0.5.
+fn g(rd: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * glicko_q().powi(2) * rd.powi(2) / PI.powi(2)).sqrt()
+}
+
+/// Expected score of a party rated `rating` against an opponent rated `opponent_rating` with
+/// deviation `opponent_rd`.
+fn expected_score(rating: f64, opponent_rating: f64, opponent_rd: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-g(opponent_rd) * (rating - opponent_rating) / 400.0))
+}
+
+/// Runs an `iterations`-trial Monte Carlo survey of `players` against `encounters` under
+/// `seed` and reduces it to the win rate / median score pair a "match" is scored from.
+fn survey_party(players: &[Creature], encounters: &[Encounter], iterations: usize, seed: u64) -> PartyPerformance {
+    let results = simulation::run_monte_carlo(players, encounters, iterations, seed);
+    if results.is_empty() {
+        return PartyPerformance { win_rate: 0.0, median_score: 0.0 };
+    }
+
+    let win_rate = results.iter().filter(|result| simulation::run_is_win(result)).count() as f64 / results.len() as f64;
+
+    let mut scores: Vec<f64> = results.iter().filter_map(|result| result.score).collect();
+    scores.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median_score = scores.get(scores.len() / 2).copied().unwrap_or(0.0);
+
+    PartyPerformance { win_rate, median_score }
+}
+
+/// `1.0` if `a` beat `b` this period, `0.0` if it lost, `0.5` on a tie. Win rate is the primary
+/// signal; median score only breaks a win-rate tie, since win rate is what the request is
+/// ultimately ranking builds on.
+fn match_outcome(a: PartyPerformance, b: PartyPerformance) -> f64 {
+    if (a.win_rate - b.win_rate).abs() > 1e-9 {
+        return if a.win_rate > b.win_rate { 1.0 } else { 0.0 };
+    }
+    if (a.median_score - b.median_score).abs() > 1e-9 {
+        return if a.median_score > b.median_score { 1.0 } else { 0.0 };
+    }
+    0.5
+}
+
+/// Ranks `parties` by playing each one's build through every timeline in `timelines` (each
+/// timeline a fixed sequence of `Encounter`s) and feeding the round-robin outcomes into a
+/// Glicko-1 batch update, one rating period per timeline. Every party in a period updates
+/// against every other party's *pre-period* rating - not a partially-updated one - matching
+/// Glicko's batch formulation. The same `seed` is reused for every party on every timeline so
+/// the two-pass determinism the request calls out (no RNG divergence between candidates)
+/// holds: any rating difference traces back to the build, not the dice.
+pub fn rate_parties(
+    parties: &[PartyCandidate],
+    timelines: &[Vec<Encounter>],
+    iterations_per_probe: usize,
+    seed: u64,
+) -> Vec<LeaderboardEntry> {
+    let n = parties.len();
+    let mut ratings: Vec<RatingState> = vec![
+        RatingState { rating: INITIAL_RATING, rd: INITIAL_RD, win_rate_sum: 0.0, periods: 0.0 };
+        n
+    ];
+
+    for encounters in timelines {
+        let performances: Vec<PartyPerformance> = parties
+            .iter()
+            .map(|party| survey_party(&party.players, encounters, iterations_per_probe, seed))
+            .collect();
+
+        let mut updated = ratings.clone();
+        for i in 0..n {
+            let mut d_sq_inv = 0.0;
+            let mut sum_term = 0.0;
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let opponent = ratings[j];
+                let g_j = g(opponent.rd);
+                let e_j = expected_score(ratings[i].rating, opponent.rating, opponent.rd);
+                let s_j = match_outcome(performances[i], performances[j]);
+                d_sq_inv += g_j.powi(2) * e_j * (1.0 - e_j);
+                sum_term += g_j * (s_j - e_j);
+            }
+            d_sq_inv *= glicko_q().powi(2);
+
+            if d_sq_inv > 0.0 {
+                let d_sq = 1.0 / d_sq_inv;
+                let precision = 1.0 / ratings[i].rd.powi(2) + 1.0 / d_sq;
+                updated[i].rating = ratings[i].rating + glicko_q() / precision * sum_term;
+                updated[i].rd = (1.0 / precision).sqrt().min(MAX_RD);
+            }
+            updated[i].win_rate_sum += performances[i].win_rate;
+            updated[i].periods += 1.0;
+        }
+        ratings = updated;
+    }
+
+    let mut leaderboard: Vec<LeaderboardEntry> = parties
+        .iter()
+        .zip(&ratings)
+        .map(|(party, r)| LeaderboardEntry {
+            name: party.name.clone(),
+            rating: r.rating,
+            rating_deviation: r.rd,
+            win_rate: if r.periods > 0.0 { r.win_rate_sum / r.periods } else { 0.0 },
+        })
+        .collect();
+    leaderboard.sort_by(|a, b| b.rating.partial_cmp(&a.rating).unwrap());
+    leaderboard
+}
@@ -0,0 +1,271 @@
+// Monte Carlo Tree Search action planner: an alternative to scripted action-slot AI.
+//
+// Scope: the search is single-ply. The root's children are the candidate action-economy
+// bundles (Action slot + Bonus-Action slot) `get_actions`/`get_targets` offer the acting
+// combatant this turn; each child's value comes from a rollout that applies the bundle and
+// then lets the rest of the encounter play out with the existing scripted AI (`run_round`).
+// A true multi-ply tree (searching this combatant's future turns too) would need the turn
+// loop itself to be resumable, which `execute_turn`/`run_round` aren't today.
+use std::collections::HashMap;
+use crate::model::*;
+use crate::actions::get_actions;
+use crate::targeting::get_targets;
+use crate::resolution;
+use crate::simulation;
+
+const EXPLORATION_CONSTANT: f64 = std::f64::consts::SQRT_2;
+const MAX_ROUNDS: usize = 20; // Matches run_encounter's round cap.
+/// Default iteration budget for one `choose_action_mcts` call, used when a creature doesn't
+/// override it via `Creature::mcts_iterations`. Fixed rather than time-based so results stay
+/// reproducible under the same RNG seed.
+pub(crate) const DEFAULT_ITERATIONS: usize = 200;
+
+thread_local! {
+    // Set for the duration of a `choose_action_mcts` call so that any `AiMode::Mcts`
+    // combattant encountered during a rollout (including the searching combattant itself,
+    // on a later turn) falls back to scripted play instead of recursing into another search.
+    static IN_ROLLOUT: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
+}
+
+/// Whether the current thread is inside an MCTS rollout. `execute_turn` checks this to
+/// avoid spawning a search from within a search.
+pub fn in_rollout() -> bool {
+    IN_ROLLOUT.with(|flag| *flag.borrow())
+}
+
+struct RolloutGuard;
+
+impl Drop for RolloutGuard {
+    fn drop(&mut self) {
+        IN_ROLLOUT.with(|flag| *flag.borrow_mut() = false);
+    }
+}
+
+fn enter_rollout() -> RolloutGuard {
+    IN_ROLLOUT.with(|flag| *flag.borrow_mut() = true);
+    RolloutGuard
+}
+
+/// One action-economy slot's move for the turn: an action paired with the targets
+/// `get_targets` resolved for it.
+#[derive(Clone)]
+pub struct ActionMove {
+    pub action: Action,
+    pub targets: Vec<(bool, usize)>,
+}
+
+/// A full turn for one combatant: the Action-slot move and/or Bonus-Action-slot move the
+/// scripted economy in `execute_turn` would otherwise pick greedily. MCTS searches over
+/// these bundles (rather than single actions) so action/bonus-action synergies — e.g. a
+/// buff bonus action cast right before an attack — get scored together instead of in
+/// isolation.
+#[derive(Clone)]
+pub struct ActionBundle {
+    pub moves: Vec<ActionMove>,
+}
+
+/// One root child: a candidate bundle plus its accumulated MCTS statistics.
+struct MctsNode {
+    bundle: ActionBundle,
+    visits: u32,
+    total_score: f64,
+}
+
+fn uct(node: &MctsNode, parent_visits: u32) -> f64 {
+    if node.visits == 0 {
+        return f64::INFINITY; // Always expand an untried bundle before refining a tried one.
+    }
+    let mean_score = node.total_score / node.visits as f64;
+    mean_score + EXPLORATION_CONSTANT * ((parent_visits as f64).ln() / node.visits as f64).sqrt()
+}
+
+fn enumerate_moves(actor: &Combattant, allies: &[Combattant], enemies: &[Combattant]) -> Vec<ActionMove> {
+    get_actions(actor, allies, enemies)
+        .into_iter()
+        .filter(|action| action.base().action_slot.is_some_and(|slot| slot >= 0))
+        .filter_map(|action| {
+            let targets = get_targets(actor, &action, allies, enemies);
+            if targets.is_empty() {
+                None
+            } else {
+                Some(ActionMove { action, targets })
+            }
+        })
+        .collect()
+}
+
+/// Candidate Action-slot / Bonus-Action-slot combinations for this turn, mirroring the two
+/// action-economy slots the scripted path in `execute_turn` fills greedily: every legal
+/// Action-slot move alone, every legal Bonus-Action-slot move alone, and every pairing of
+/// the two together.
+fn enumerate_bundles(actor: &Combattant, allies: &[Combattant], enemies: &[Combattant]) -> Vec<ActionBundle> {
+    let moves = enumerate_moves(actor, allies, enemies);
+    let (action_slot, bonus_slot): (Vec<_>, Vec<_>) = moves
+        .into_iter()
+        .partition(|m| m.action.base().action_slot == Some(0));
+
+    let mut bundles = Vec::new();
+    for a in &action_slot {
+        bundles.push(ActionBundle { moves: vec![a.clone()] });
+    }
+    for b in &bonus_slot {
+        bundles.push(ActionBundle { moves: vec![b.clone()] });
+    }
+    for a in &action_slot {
+        for b in &bonus_slot {
+            bundles.push(ActionBundle { moves: vec![a.clone(), b.clone()] });
+        }
+    }
+    bundles
+}
+
+fn team_hp_fraction(team: &[Combattant]) -> f64 {
+    let max_hp: f64 = team.iter().map(|c| c.creature.hp as f64).sum();
+    if max_hp <= 0.0 {
+        return 0.0;
+    }
+    let current_hp: f64 = team.iter().map(|c| c.final_state.current_hp as f64).sum();
+    (current_hp / max_hp).clamp(0.0, 1.0)
+}
+
+/// Score a terminal (or round-cap-truncated) encounter state as the allies' HP share minus
+/// the enemies', in `[-1.0, 1.0]`. A costly win (allies battered, enemies wiped) still beats
+/// a cheap stalemate, and a Pyrrhic trade scores near zero instead of looking like a clean win.
+fn encounter_reward(team1: &[Combattant], team2: &[Combattant]) -> f64 {
+    team_hp_fraction(team1) - team_hp_fraction(team2)
+}
+
+/// Roll the remaining rounds forward on a cloned encounter state using the normal scripted
+/// AI, then score the outcome. The round the candidate bundle was taken in is treated as
+/// already resolved; the rollout resumes at the start of the next round.
+fn rollout_reward(mut team1: Vec<Combattant>, mut team2: Vec<Combattant>, mut stats: HashMap<String, EncounterStats>, next_round: usize) -> f64 {
+    let mut log = Vec::new();
+    for round_num in next_round..=MAX_ROUNDS {
+        if !team1.iter().any(|c| c.final_state.current_hp > 0) || !team2.iter().any(|c| c.final_state.current_hp > 0) {
+            break;
+        }
+        let round = simulation::run_round(&team1, &team2, &mut stats, &mut log, false, round_num);
+        team1 = round.team1;
+        team2 = round.team2;
+    }
+    encounter_reward(&team1, &team2)
+}
+
+/// Expand and simulate one candidate bundle: clone the encounter state, apply each of its
+/// moves in order, then hand off to `rollout_reward` for the rest of the encounter. Attack
+/// rolls draw fresh from the shared RNG stream on every call (nothing here reseeds or
+/// caches a roll), so repeat visits to the same bundle average its stochastic outcomes
+/// correctly instead of replaying one sampled result.
+fn simulate_bundle(
+    index: usize,
+    bundle: &ActionBundle,
+    allies: &[Combattant],
+    enemies: &[Combattant],
+    stats: &HashMap<String, EncounterStats>,
+    round_num: usize,
+) -> f64 {
+    let mut allies = allies.to_vec();
+    let mut enemies = enemies.to_vec();
+    let mut stats = stats.clone();
+    let mut log = Vec::new();
+
+    for mv in &bundle.moves {
+        let mut action_record = CombattantAction {
+            action: mv.action.clone(),
+            targets: HashMap::new(),
+        };
+        for (is_enemy, target_idx) in &mv.targets {
+            let id = if *is_enemy { &enemies[*target_idx].id } else { &allies[*target_idx].id };
+            *action_record.targets.entry(id.clone()).or_insert(0) += 1;
+        }
+
+        let cleanup = resolution::resolve_action_execution(
+            index,
+            &mut allies,
+            &mut enemies,
+            &mv.action,
+            &mv.targets,
+            &action_record,
+            &mut stats,
+            &mut log,
+            false,
+        );
+        for instruction in cleanup {
+            match instruction {
+                CleanupInstruction::RemoveAllBuffsFromSource(source_id) => {
+                    crate::actions::remove_all_buffs_from_source(&source_id, &mut allies, &mut enemies);
+                }
+                CleanupInstruction::BreakConcentration(combatant_id, buff_id) => {
+                    crate::actions::break_concentration(&combatant_id, &buff_id, &mut allies, &mut enemies);
+                }
+                CleanupInstruction::TriggerOnDeath(source_id) => {
+                    crate::cleanup::apply_on_death_triggers(&source_id, &mut allies, &mut enemies, &mut stats, &mut log, false, None);
+                }
+            }
+        }
+    }
+
+    rollout_reward(allies, enemies, stats, round_num + 1)
+}
+
+/// Run a fixed-iteration Monte Carlo Tree Search to pick `allies[index]`'s action-economy
+/// bundle for the current turn, using the scripted AI as the rollout policy.
+///
+/// The four MCTS phases map onto this single-ply search as: Selection walks the candidate
+/// bundles by UCT (an untried bundle is always `+infinity`, so every bundle gets tried once
+/// before any is revisited); Expansion is that first visit — there's no further tree below a
+/// bundle, since the decision being searched is this one turn; Simulation is
+/// `simulate_bundle`'s scripted rollout to encounter termination; Backpropagation folds the
+/// resulting reward into that bundle's visit count and score total. Returns `None` when no
+/// bundle has a valid target this turn (mirroring the scripted AI's "no actions available"
+/// case), or when the acting creature's `mcts_iterations` budget resolves to `0` - in that
+/// case the caller falls back to the scripted path instead (see `execute_turn`).
+pub fn choose_action_mcts(
+    index: usize,
+    allies: &[Combattant],
+    enemies: &[Combattant],
+    stats: &HashMap<String, EncounterStats>,
+    round_num: usize,
+) -> Option<ActionBundle> {
+    let iterations = allies[index].creature.mcts_iterations.unwrap_or(DEFAULT_ITERATIONS);
+    if iterations == 0 {
+        return None;
+    }
+
+    let bundles = enumerate_bundles(&allies[index], allies, enemies);
+    if bundles.len() <= 1 {
+        return bundles.into_iter().next();
+    }
+
+    let _guard = enter_rollout();
+
+    let mut nodes: Vec<MctsNode> = bundles
+        .into_iter()
+        .map(|bundle| MctsNode { bundle, visits: 0, total_score: 0.0 })
+        .collect();
+
+    for _ in 0..iterations {
+        let parent_visits: u32 = nodes.iter().map(|n| n.visits).sum();
+
+        // Selection.
+        let chosen = nodes
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                uct(a, parent_visits)
+                    .partial_cmp(&uct(b, parent_visits))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i)
+            .unwrap();
+
+        // Expansion + Simulation.
+        let reward = simulate_bundle(index, &nodes[chosen].bundle, allies, enemies, stats, round_num);
+
+        // Backpropagation.
+        nodes[chosen].visits += 1;
+        nodes[chosen].total_score += reward;
+    }
+
+    nodes.into_iter().max_by_key(|n| n.visits).map(|n| n.bundle)
+}
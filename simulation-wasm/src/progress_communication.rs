@@ -2,7 +2,7 @@ use crate::background_simulation::{BackgroundSimulationId, SimulationProgress};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{mpsc, Arc, Mutex, PoisonError};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Types of progress updates that can be sent
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +17,15 @@ pub enum ProgressUpdateType {
     Failed,
     /// Simulation was cancelled
     Cancelled,
+    /// Synthetic update broadcast by the stall watchdog: no real phase change has been observed
+    /// for `since_ms` milliseconds
+    Stalled { since_ms: u64 },
+    /// Synthetic update broadcast by the stall watchdog when a simulation exceeded its
+    /// `max_runtime_ms` budget - its concurrency slot has been freed
+    TimedOut,
+    /// The request's scenario failed validation at dequeue time and was never run - carries the
+    /// same `field_path` as `SimulationRequestStatus::InvalidJob`
+    InvalidJob { field_path: Option<String> },
 }
 
 /// A progress update that can be sent to subscribers
@@ -123,6 +132,8 @@ impl ProgressUpdate {
             ProgressUpdateType::Completed
                 | ProgressUpdateType::Failed
                 | ProgressUpdateType::Cancelled
+                | ProgressUpdateType::TimedOut
+                | ProgressUpdateType::InvalidJob { .. }
         )
     }
 
@@ -130,7 +141,10 @@ impl ProgressUpdate {
     pub fn is_error(&self) -> bool {
         matches!(
             self.update_type,
-            ProgressUpdateType::Failed | ProgressUpdateType::Cancelled
+            ProgressUpdateType::Failed
+                | ProgressUpdateType::Cancelled
+                | ProgressUpdateType::TimedOut
+                | ProgressUpdateType::InvalidJob { .. }
         )
     }
 
@@ -287,15 +301,37 @@ impl ProgressSubscription {
         let type_priority = |t: &ProgressUpdateType| match t {
             ProgressUpdateType::Started => 0,
             ProgressUpdateType::Progress => 1,
+            ProgressUpdateType::Stalled { .. } => 1,
             ProgressUpdateType::Completed => 2,
             ProgressUpdateType::Failed => 3,
             ProgressUpdateType::Cancelled => 4,
+            ProgressUpdateType::TimedOut => 4,
+            ProgressUpdateType::InvalidJob { .. } => 4,
         };
 
         type_priority(update_type) >= type_priority(&self.min_update_type)
     }
 }
 
+/// Tracks when a simulation last produced a genuine (non-watchdog) progress update, for the
+/// stall/timeout watchdog in `storage_integration`.
+#[derive(Debug, Clone)]
+struct SimulationActivity {
+    started_at: Instant,
+    last_update_at: Instant,
+    last_phase: String,
+}
+
+/// A point-in-time read of one simulation's watchdog bookkeeping - how long it's been running,
+/// how long since its last genuine phase change, and what that phase was.
+#[derive(Debug, Clone)]
+pub struct SimulationActivitySnapshot {
+    pub simulation_id: BackgroundSimulationId,
+    pub elapsed_ms: u64,
+    pub since_last_update_ms: u64,
+    pub last_phase: String,
+}
+
 /// Thread-safe progress communication system
 pub struct ProgressCommunication {
     /// Channel for broadcasting updates
@@ -304,6 +340,8 @@ pub struct ProgressCommunication {
     subscriptions: Arc<Mutex<HashMap<String, ProgressSubscription>>>,
     /// Channel receivers for each subscription
     subscription_channels: Arc<Mutex<HashMap<String, mpsc::Sender<ProgressUpdate>>>>,
+    /// Last-seen activity per simulation, read by the stall/timeout watchdog
+    activity: Arc<Mutex<HashMap<BackgroundSimulationId, SimulationActivity>>>,
 }
 
 impl ProgressCommunication {
@@ -315,6 +353,7 @@ impl ProgressCommunication {
             update_sender,
             subscriptions: Arc::new(Mutex::new(HashMap::new())),
             subscription_channels: Arc::new(Mutex::new(HashMap::new())),
+            activity: Arc::new(Mutex::new(HashMap::new())),
         };
 
         (system, update_receiver)
@@ -322,6 +361,26 @@ impl ProgressCommunication {
 
     /// Send a progress update to all matching subscribers
     pub fn send_update(&self, update: ProgressUpdate) -> Result<(), ProgressError> {
+        // Record activity for the stall/timeout watchdog. Synthetic `Stalled`/`TimedOut`
+        // updates (the watchdog's own broadcasts) don't reset the clock they're reporting on.
+        let is_synthetic = matches!(
+            update.update_type,
+            ProgressUpdateType::Stalled { .. } | ProgressUpdateType::TimedOut
+        );
+        if !is_synthetic {
+            let now = Instant::now();
+            let mut activity = self.activity.lock().unwrap_or_else(PoisonError::into_inner);
+            let entry = activity
+                .entry(update.simulation_id.clone())
+                .or_insert_with(|| SimulationActivity {
+                    started_at: now,
+                    last_update_at: now,
+                    last_phase: update.current_phase.clone(),
+                });
+            entry.last_update_at = now;
+            entry.last_phase = update.current_phase.clone();
+        }
+
         // Send to the main broadcast channel
         self.update_sender
             .send(update.clone())
@@ -349,6 +408,31 @@ impl ProgressCommunication {
         Ok(())
     }
 
+    /// Snapshot every tracked simulation's elapsed/since-last-update times, for
+    /// `StorageIntegration::get_integration_stats` and the stall/timeout watchdog.
+    pub fn activity_snapshots(&self) -> Vec<SimulationActivitySnapshot> {
+        let activity = self.activity.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        activity
+            .iter()
+            .map(|(simulation_id, entry)| SimulationActivitySnapshot {
+                simulation_id: simulation_id.clone(),
+                elapsed_ms: now.duration_since(entry.started_at).as_millis() as u64,
+                since_last_update_ms: now.duration_since(entry.last_update_at).as_millis() as u64,
+                last_phase: entry.last_phase.clone(),
+            })
+            .collect()
+    }
+
+    /// Stop tracking a simulation once it's finished - called by the watchdog after it marks a
+    /// simulation `TimedOut`, and usable by any other terminal-update path.
+    pub fn clear_activity(&self, simulation_id: &BackgroundSimulationId) {
+        self.activity
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(simulation_id);
+    }
+
     /// Subscribe to progress updates
     pub fn subscribe(
         &self,
@@ -466,9 +550,15 @@ impl Default for ProgressCommunication {
 
 impl Clone for ProgressCommunication {
     fn clone(&self) -> Self {
-        // Create a new communication system - this is a simplified clone
-        // In a real implementation, you might want to share same channels
-        Self::default()
+        // Shares the same channel sender, subscriptions, and activity map as the original -
+        // needed so a background watchdog thread (see `storage_integration::spawn_stall_monitor`)
+        // observes and reports on the exact same update stream, not an independent copy.
+        Self {
+            update_sender: self.update_sender.clone(),
+            subscriptions: Arc::clone(&self.subscriptions),
+            subscription_channels: Arc::clone(&self.subscription_channels),
+            activity: Arc::clone(&self.activity),
+        }
     }
 }
 
@@ -668,4 +758,70 @@ mod tests {
         assert!(formatted.contains("(75/100)"));
         assert!(formatted.contains("Running"));
     }
+
+    #[test]
+    fn test_activity_tracking_records_genuine_updates() {
+        let (comm, _receiver) = ProgressCommunication::new();
+        let sim_id = BackgroundSimulationId::new();
+
+        comm.send_update(ProgressUpdate::new(
+            sim_id.clone(),
+            ProgressUpdateType::Progress,
+            0.2,
+            "Phase1",
+        ))
+        .unwrap();
+
+        let snapshots = comm.activity_snapshots();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].simulation_id, sim_id);
+        assert_eq!(snapshots[0].last_phase, "Phase1");
+
+        comm.clear_activity(&sim_id);
+        assert!(comm.activity_snapshots().is_empty());
+    }
+
+    #[test]
+    fn test_synthetic_updates_do_not_reset_activity_clock() {
+        let (comm, _receiver) = ProgressCommunication::new();
+        let sim_id = BackgroundSimulationId::new();
+
+        comm.send_update(ProgressUpdate::new(
+            sim_id.clone(),
+            ProgressUpdateType::Progress,
+            0.2,
+            "Phase1",
+        ))
+        .unwrap();
+        comm.send_update(ProgressUpdate::new(
+            sim_id.clone(),
+            ProgressUpdateType::Stalled { since_ms: 5000 },
+            0.2,
+            "Stalled",
+        ))
+        .unwrap();
+
+        let snapshots = comm.activity_snapshots();
+        assert_eq!(snapshots.len(), 1);
+        // The synthetic update must not overwrite the last genuine phase.
+        assert_eq!(snapshots[0].last_phase, "Phase1");
+    }
+
+    #[test]
+    fn test_clone_shares_activity_state() {
+        let (comm, _receiver) = ProgressCommunication::new();
+        let clone = comm.clone();
+        let sim_id = BackgroundSimulationId::new();
+
+        comm.send_update(ProgressUpdate::new(
+            sim_id.clone(),
+            ProgressUpdateType::Progress,
+            0.1,
+            "Phase1",
+        ))
+        .unwrap();
+
+        // The clone observes the same activity map, not an independent one.
+        assert_eq!(clone.activity_snapshots().len(), 1);
+    }
 }
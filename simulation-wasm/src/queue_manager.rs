@@ -1,8 +1,49 @@
 use crate::background_simulation::{SimulationPriority};
 use crate::user_interaction::ScenarioParameters;
 use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::error::Error as StdError;
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Upper bound on retry backoff, regardless of how many attempts have already been made.
+const MAX_RETRY_BACKOFF_MS: u64 = 60_000;
+
+/// Where a request currently stands, tracked per `request_id` so `get_request_status` can answer
+/// after the request has already left the pending/processing sets (e.g. `InvalidJob`/`Failed`).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SimulationRequestStatus {
+    /// Waiting in the priority queue
+    Queued,
+    /// Handed out by `dequeue` and currently being run
+    Running,
+    /// Ran to completion successfully
+    Completed,
+    /// Exhausted its retries; carries the last error
+    Failed { error: String },
+    /// The scenario failed validation at dequeue time and was never run. `field_path` is the
+    /// failing field, when the `serde_json` error chain exposes one.
+    InvalidJob {
+        error: String,
+        field_path: Option<String>,
+    },
+    /// Ran past `spawn_stall_monitor`'s `max_runtime_ms` and was given up on
+    TimedOut,
+}
+
+/// Re-serializes and re-deserializes `parameters` the same way it would be parsed from JSON at
+/// submission time, so a scenario that's only valid as an in-memory value (e.g. built by
+/// bypassing the normal submission path) fails here with a diagnosable error instead of later,
+/// mid-simulation. Walks the `source()` chain the way `debug_full_player_tests` does to recover
+/// which nested field broke, when `serde_json` exposes one.
+pub fn validate_scenario(parameters: &ScenarioParameters) -> Result<(), (String, Option<String>)> {
+    let json = serde_json::to_string(parameters).map_err(|e| (e.to_string(), None))?;
+    serde_json::from_str::<ScenarioParameters>(&json)
+        .map(|_| ())
+        .map_err(|e| {
+            let field_path = e.source().map(|source| source.to_string());
+            (e.to_string(), field_path)
+        })
+}
 
 /// A request to run a simulation with specific parameters
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +60,20 @@ pub struct SimulationRequest {
     pub progress_callback: Option<String>,
     /// Whether this request can be deduplicated
     pub allow_deduplication: bool,
+    /// How many times this request has already been retried after a failed attempt
+    pub retry_count: u32,
+    /// Maximum number of retries before the request is given up as `Failed`
+    pub max_retries: u32,
+    /// Base backoff in milliseconds; the actual delay is `base * 2^retry_count`, capped at
+    /// `MAX_RETRY_BACKOFF_MS`
+    pub retry_backoff_base_ms: u64,
+    /// Earliest time this request may be handed out by `dequeue` - used to implement the
+    /// retry backoff delay without needing a separate delay queue
+    pub available_at: Instant,
+    /// When this request first entered the queue - the basis for priority aging and for the
+    /// FIFO tie-break between requests at the same effective priority. Unlike `available_at`,
+    /// a retry never resets this.
+    pub enqueued_at: Instant,
 }
 
 impl SimulationRequest {
@@ -37,6 +92,11 @@ impl SimulationRequest {
                 .as_secs(),
             progress_callback: None,
             allow_deduplication: true,
+            retry_count: 0,
+            max_retries: 0,
+            retry_backoff_base_ms: 500,
+            available_at: Instant::now(),
+            enqueued_at: Instant::now(),
         }
 
     }
@@ -53,6 +113,19 @@ impl SimulationRequest {
         self
     }
 
+    /// Configure how many times this request may be automatically retried on failure, and how
+    /// long the exponential backoff between attempts starts at
+    pub fn with_retry_policy(mut self, max_retries: u32, retry_backoff_base_ms: u64) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff_base_ms = retry_backoff_base_ms;
+        self
+    }
+
+    /// Whether `available_at` has elapsed, i.e. this request is eligible to be dequeued
+    pub fn is_available(&self) -> bool {
+        Instant::now() >= self.available_at
+    }
+
     /// Calculate a hash for deduplication purposes
     pub fn deduplication_hash(&self) -> String {
         use std::collections::hash_map::DefaultHasher;
@@ -118,6 +191,7 @@ impl PartialOrd for PriorityRequest {
 }
 
 /// Thread-safe queue for managing simulation requests
+#[derive(Clone)]
 pub struct SimulationQueue {
     /// Priority queue of pending requests
     pending_requests: Arc<Mutex<BinaryHeap<PriorityRequest>>>,
@@ -129,6 +203,11 @@ pub struct SimulationQueue {
     insertion_counter: Arc<Mutex<u64>>,
     /// Maximum queue size
     max_queue_size: usize,
+    /// Last-known status per `request_id`, surfaced through `get_request_status`
+    statuses: Arc<Mutex<HashMap<String, SimulationRequestStatus>>>,
+    /// How often a pending request's effective priority is boosted one level while it waits -
+    /// `0` disables aging entirely (the default)
+    aging_interval_ms: u64,
 }
 
 impl SimulationQueue {
@@ -140,9 +219,57 @@ impl SimulationQueue {
             deduplication_map: Arc::new(Mutex::new(HashMap::new())),
             insertion_counter: Arc::new(Mutex::new(0)),
             max_queue_size,
+            statuses: Arc::new(Mutex::new(HashMap::new())),
+            aging_interval_ms: 0,
         }
     }
 
+    /// Enable priority aging: every `interval_ms` a pending request has waited, its effective
+    /// priority (see `effective_priority`) is boosted one level, capped at `High` so nothing
+    /// ages all the way to `Critical` and jumps ahead of genuinely critical work.
+    pub fn with_aging_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.aging_interval_ms = interval_ms;
+        self
+    }
+
+    /// The priority `dequeue` will actually use for `request` right now: its base `priority`,
+    /// boosted one level for every `aging_interval_ms` it's been waiting (disabled when
+    /// `aging_interval_ms` is `0`), capped at `High` so aging never outranks a genuinely
+    /// `Critical` request and never lowers a request's priority below where it started.
+    pub fn effective_priority(&self, request: &SimulationRequest) -> SimulationPriority {
+        if self.aging_interval_ms == 0 {
+            return request.priority;
+        }
+
+        let elapsed_ms = request.enqueued_at.elapsed().as_millis() as u64;
+        let boost_levels = (elapsed_ms / self.aging_interval_ms) as u8;
+        let base = request.priority as u8;
+        let boosted = base
+            .saturating_add(boost_levels)
+            .min(SimulationPriority::High as u8)
+            .max(base);
+
+        match boosted {
+            0 => SimulationPriority::Low,
+            1 => SimulationPriority::Normal,
+            2 => SimulationPriority::High,
+            _ => SimulationPriority::Critical,
+        }
+    }
+
+    /// Record the current status of `request_id`, overwriting whatever was there before
+    pub fn set_status(&self, request_id: &str, status: SimulationRequestStatus) {
+        self.statuses
+            .lock()
+            .unwrap()
+            .insert(request_id.to_string(), status);
+    }
+
+    /// Look up the last-known status of `request_id`, if it's ever been enqueued on this queue
+    pub fn get_request_status(&self, request_id: &str) -> Option<SimulationRequestStatus> {
+        self.statuses.lock().unwrap().get(request_id).cloned()
+    }
+
     /// Add a new request to the queue
     pub fn enqueue(&self, request: SimulationRequest) -> Result<(), QueueError> {
         // Check queue size limit
@@ -185,27 +312,92 @@ impl SimulationQueue {
             insertion_order,
         };
 
+        let request_id = priority_request.request.request_id.clone();
         let mut pending = self.pending_requests.lock().unwrap();
         pending.push(priority_request);
+        drop(pending);
 
+        self.set_status(&request_id, SimulationRequestStatus::Queued);
         Ok(())
     }
 
-    /// Get the next request from the queue (highest priority first)
+    /// Get the next request from the queue, selecting by *effective* (aging-boosted) priority -
+    /// see `effective_priority` - rather than the fixed priority it was inserted with, skipping
+    /// any request whose `available_at` (set by `retry_or_exhaust`'s backoff) hasn't elapsed yet.
+    /// Ties on effective priority break by `enqueued_at` (FIFO). Every non-selected request is
+    /// pushed back onto the heap unchanged.
     pub fn dequeue(&self) -> Option<SimulationRequest> {
         let mut pending = self.pending_requests.lock().unwrap();
-        
-        if let Some(priority_request) = pending.pop() {
-            let request = priority_request.request;
-            
+
+        let mut candidates = Vec::new();
+        while let Some(priority_request) = pending.pop() {
+            candidates.push(priority_request);
+        }
+
+        let mut best_index = None;
+        for (index, candidate) in candidates.iter().enumerate() {
+            if !candidate.request.is_available() {
+                continue;
+            }
+            let candidate_key = (
+                self.effective_priority(&candidate.request),
+                std::cmp::Reverse(candidate.request.enqueued_at),
+            );
+            let is_better = match best_index {
+                None => true,
+                Some(best) => {
+                    let best: &PriorityRequest = &candidates[best];
+                    let best_key = (
+                        self.effective_priority(&best.request),
+                        std::cmp::Reverse(best.request.enqueued_at),
+                    );
+                    candidate_key > best_key
+                }
+            };
+            if is_better {
+                best_index = Some(index);
+            }
+        }
+
+        let found = best_index.map(|index| candidates.remove(index).request);
+        for priority_request in candidates {
+            pending.push(priority_request);
+        }
+
+        if let Some(request) = &found {
             // Mark as processing
             let mut processing = self.processing_requests.lock().unwrap();
             processing.insert(request.request_id.clone());
-            
-            Some(request)
-        } else {
-            None
+            drop(processing);
+            self.set_status(&request.request_id, SimulationRequestStatus::Running);
+        }
+
+        found
+    }
+
+    /// Handle a failed attempt at `request`: if it's still under its retry cap, bump
+    /// `retry_count`, push `available_at` out by `retry_backoff_base_ms * 2^retry_count`
+    /// (capped at `MAX_RETRY_BACKOFF_MS`), and re-enqueue it so `dequeue` picks it up again once
+    /// the backoff elapses. Returns the request back to the caller once retries are exhausted,
+    /// so it can be transitioned to `Failed` with the last error instead.
+    pub fn retry_or_exhaust(&self, mut request: SimulationRequest) -> Result<(), SimulationRequest> {
+        if request.retry_count >= request.max_retries {
+            return Err(request);
         }
+
+        let backoff_ms = request
+            .retry_backoff_base_ms
+            .saturating_mul(1u64 << request.retry_count.min(16))
+            .min(MAX_RETRY_BACKOFF_MS);
+        request.retry_count += 1;
+        request.available_at = Instant::now() + Duration::from_millis(backoff_ms);
+
+        // This request is re-entering the queue under the same request_id - drop its
+        // processing-set membership first so `enqueue`'s dedup check doesn't see it as already
+        // in flight.
+        self.processing_requests.lock().unwrap().remove(&request.request_id);
+        let _ = self.enqueue(request);
+        Ok(())
     }
 
     /// Mark a request as completed (remove from processing set)
@@ -263,12 +455,26 @@ impl SimulationQueue {
             *priority_counts.entry(priority_request.request.priority).or_insert(0) += 1;
         }
 
+        let pending_aging = pending
+            .iter()
+            .map(|priority_request| {
+                let request = &priority_request.request;
+                PendingRequestAging {
+                    request_id: request.request_id.clone(),
+                    base_priority: request.priority,
+                    effective_priority: self.effective_priority(request),
+                    wait_ms: request.enqueued_at.elapsed().as_millis() as u64,
+                }
+            })
+            .collect();
+
         QueueStats {
             pending_count: pending.len(),
             processing_count: processing.len(),
             total_capacity: self.max_queue_size,
             priority_counts,
             deduplication_cache_size: dedup_map.len(),
+            pending_aging,
         }
     }
 
@@ -333,6 +539,18 @@ pub struct QueueStats {
     pub priority_counts: HashMap<SimulationPriority, usize>,
     /// Size of deduplication cache
     pub deduplication_cache_size: usize,
+    /// Per-pending-request base vs. effective (aging-boosted) priority and current wait time, so
+    /// callers can see which jobs have been promoted
+    pub pending_aging: Vec<PendingRequestAging>,
+}
+
+/// One pending request's aging state, as reported by `SimulationQueue::get_stats`.
+#[derive(Debug, Clone)]
+pub struct PendingRequestAging {
+    pub request_id: String,
+    pub base_priority: SimulationPriority,
+    pub effective_priority: SimulationPriority,
+    pub wait_ms: u64,
 }
 
 /// Errors that can occur during queue operations
@@ -377,6 +595,9 @@ pub struct QueueManagerConfig {
     pub enable_deduplication: bool,
     /// Queue processing interval in milliseconds
     pub processing_interval_ms: u64,
+    /// How often a pending request's effective priority is boosted one level while it waits -
+    /// `0` disables aging entirely. See `SimulationQueue::effective_priority`.
+    pub aging_interval_ms: u64,
 }
 
 impl Default for QueueManagerConfig {
@@ -387,6 +608,7 @@ impl Default for QueueManagerConfig {
             default_priority: SimulationPriority::Normal,
             enable_deduplication: true,
             processing_interval_ms: 100,
+            aging_interval_ms: 0,
         }
     }
 }
@@ -403,7 +625,8 @@ impl QueueManager {
     /// Create a new queue manager
     pub fn new(config: QueueManagerConfig) -> Self {
         Self {
-            queue: SimulationQueue::new(config.max_queue_size),
+            queue: SimulationQueue::new(config.max_queue_size)
+                .with_aging_interval_ms(config.aging_interval_ms),
             config,
         }
     }
@@ -418,6 +641,11 @@ impl QueueManager {
         self.queue.dequeue()
     }
 
+    /// Retry or give up on a failed request - see `SimulationQueue::retry_or_exhaust`
+    pub fn retry_or_exhaust(&self, request: SimulationRequest) -> Result<(), SimulationRequest> {
+        self.queue.retry_or_exhaust(request)
+    }
+
     /// Get queue statistics
     pub fn get_stats(&self) -> QueueStats {
         self.queue.get_stats()
@@ -450,6 +678,11 @@ impl QueueManager {
         self.queue.mark_completed(request_id);
     }
 
+    /// Look up the last-known status of a request
+    pub fn get_request_status(&self, request_id: &str) -> Option<SimulationRequestStatus> {
+        self.queue.get_request_status(request_id)
+    }
+
     /// Check if a request is pending
     pub fn is_pending(&self, request_id: &str) -> bool {
         self.queue.is_pending(request_id)
@@ -478,11 +711,13 @@ impl Default for QueueManager {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use crate::model::{Creature, DiceFormula};
 
-    fn create_test_creature(name: &str, hp: f64, ac: f64) -> Creature {
+    /// Shared across this module's and `storage_integration`'s tests - both need a minimal,
+    /// valid `Creature` to build a `ScenarioParameters`/`SimulationRequest` with.
+    pub(crate) fn create_test_creature(name: &str, hp: f64, ac: f64) -> Creature {
         Creature {
             id: name.to_string(),
             arrival: None,
@@ -631,8 +866,133 @@ mod tests {
         
         // Mark as completed
         queue.mark_completed(&request_id);
-        
+
         // Should no longer be processing
         assert!(!queue.is_processing(&request_id));
     }
+
+    #[test]
+    fn test_retry_reschedules_with_backoff() {
+        let queue = SimulationQueue::new(10);
+
+        let request = SimulationRequest::new(create_test_parameters(100), SimulationPriority::Normal)
+            .with_retry_policy(2, 10);
+        let request_id = request.request_id.clone();
+        queue.enqueue(request).unwrap();
+
+        let dequeued = queue.dequeue().unwrap();
+        assert_eq!(dequeued.retry_count, 0);
+
+        // Still under the cap - gets rescheduled rather than handed back as exhausted.
+        assert!(queue.retry_or_exhaust(dequeued).is_ok());
+
+        // Not available yet: the backoff delay hasn't elapsed.
+        assert!(queue.dequeue().is_none());
+        assert!(queue.is_pending(&request_id));
+
+        std::thread::sleep(std::time::Duration::from_millis(15));
+        let retried = queue.dequeue().unwrap();
+        assert_eq!(retried.retry_count, 1);
+    }
+
+    #[test]
+    fn test_retry_exhausted_after_max_retries() {
+        let queue = SimulationQueue::new(10);
+
+        let request = SimulationRequest::new(create_test_parameters(100), SimulationPriority::Normal)
+            .with_retry_policy(0, 10);
+        queue.enqueue(request).unwrap();
+
+        let dequeued = queue.dequeue().unwrap();
+        let result = queue.retry_or_exhaust(dequeued);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().retry_count, 0);
+    }
+
+    #[test]
+    fn test_status_tracking_through_queued_running_completed() {
+        let queue = SimulationQueue::new(10);
+
+        let request = SimulationRequest::new(create_test_parameters(100), SimulationPriority::Normal);
+        let request_id = request.request_id.clone();
+
+        assert_eq!(queue.get_request_status(&request_id), None);
+
+        queue.enqueue(request).unwrap();
+        assert_eq!(
+            queue.get_request_status(&request_id),
+            Some(SimulationRequestStatus::Queued)
+        );
+
+        queue.dequeue().unwrap();
+        assert_eq!(
+            queue.get_request_status(&request_id),
+            Some(SimulationRequestStatus::Running)
+        );
+
+        queue.mark_completed(&request_id);
+        queue.set_status(&request_id, SimulationRequestStatus::Completed);
+        assert_eq!(
+            queue.get_request_status(&request_id),
+            Some(SimulationRequestStatus::Completed)
+        );
+    }
+
+    #[test]
+    fn test_validate_scenario_accepts_well_formed_parameters() {
+        let parameters = create_test_parameters(50);
+        assert!(validate_scenario(&parameters).is_ok());
+    }
+
+    #[test]
+    fn test_priority_aging_promotes_starved_low_priority_request() {
+        let queue = SimulationQueue::new(10).with_aging_interval_ms(10);
+
+        let low = SimulationRequest::new(create_test_parameters(10), SimulationPriority::Low);
+        let first_low_id = low.request_id.clone();
+        queue.enqueue(low).unwrap();
+
+        // Freshly enqueued: no boost yet, so a Normal request enqueued afterwards still wins.
+        let normal = SimulationRequest::new(create_test_parameters(20), SimulationPriority::Normal);
+        queue.enqueue(normal).unwrap();
+        let first = queue.dequeue().unwrap();
+        assert_eq!(first.parameters.iterations, 20);
+        queue.cancel_request(&first.request_id).ok();
+        queue.mark_completed(&first.request_id);
+        // The first Low request is still pending; drop it so only the re-enqueued one ages below.
+        queue.cancel_request(&first_low_id).unwrap();
+
+        // Re-enqueue a Low request and let it age past two intervals, then confirm a
+        // freshly-enqueued Normal no longer jumps ahead of it.
+        let low = SimulationRequest::new(create_test_parameters(30), SimulationPriority::Low);
+        let low_id = low.request_id.clone();
+        queue.enqueue(low).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(25));
+        assert_eq!(queue.effective_priority(&queue.peek_next().unwrap()), SimulationPriority::High);
+
+        let normal = SimulationRequest::new(create_test_parameters(40), SimulationPriority::Normal);
+        queue.enqueue(normal).unwrap();
+
+        let winner = queue.dequeue().unwrap();
+        assert_eq!(winner.request_id, low_id);
+
+        let aging = queue.get_stats().pending_aging;
+        assert_eq!(aging.len(), 1);
+        assert_eq!(aging[0].base_priority, SimulationPriority::Normal);
+        assert_eq!(aging[0].effective_priority, SimulationPriority::Normal);
+    }
+
+    #[test]
+    fn test_priority_aging_disabled_by_default() {
+        let queue = SimulationQueue::new(10);
+
+        let low = SimulationRequest::new(create_test_parameters(10), SimulationPriority::Low);
+        queue.enqueue(low).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+
+        assert_eq!(
+            queue.effective_priority(&queue.peek_next().unwrap()),
+            SimulationPriority::Low
+        );
+    }
 }
\ No newline at end of file
@@ -277,7 +277,10 @@ impl ReactionManager {
             }
             // State conditions - require combat context
             TriggerCondition::EnemyCountAtLeast { count: _ } => {
-                // TODO: Implement enemy count check from combat state
+                // TODO: Implement enemy count check from combat state. Once the full roster is
+                // threaded through here, this should call
+                // `factions::FactionTable::count_with_reaction(owner_id, owner_team, roster,
+                // Reaction::Hostile)` rather than a raw per-team headcount.
                 false
             }
             TriggerCondition::DamageExceedsPercent { threshold: _ } => {
@@ -288,6 +291,12 @@ impl ReactionManager {
                 // TODO: Implement melee attack check from event metadata
                 matches!(event, Event::AttackHit { .. })
             }
+            TriggerCondition::Script { source } => {
+                use std::hash::{Hash, Hasher};
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                source.hash(&mut hasher);
+                crate::rune_scripting::run_condition_script(hasher.finish(), source, event)
+            }
         }
     }
 
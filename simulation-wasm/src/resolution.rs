@@ -600,6 +600,17 @@ fn apply_single_effect(
                     }
                 }
 
+                // Apply damage-type resistance/vulnerability/immunity from the target's profile
+                let (type_multiplier, type_label) = if let Some(t) = target_opt.as_ref() {
+                    t.creature.damage_type_modifier(a.damage_type)
+                } else {
+                    attacker.creature.damage_type_modifier(a.damage_type)
+                };
+                total_multiplier *= type_multiplier;
+                if let Some(label) = type_label {
+                    multiplier_sources.push(label.to_string());
+                }
+
                 let _damage_before_multiplier = damage;
                 damage = (damage * total_multiplier).floor(); // Round down damage in 5e
 
@@ -725,6 +736,7 @@ fn apply_single_effect(
                     let damage_taken_by_creature = damage - ward_absorbed_amount;
 
                     if t.final_state.current_hp == 0 {
+                        cleanup_instructions.push(CleanupInstruction::TriggerOnDeath(t.id.clone()));
                         cleanup_instructions
                             .push(CleanupInstruction::RemoveAllBuffsFromSource(t.id.clone()));
                         if log_enabled {
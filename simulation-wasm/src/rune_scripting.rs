@@ -0,0 +1,144 @@
+//! Optional Rune scripting layer backing `TriggerCondition::Script` and `TriggerEffect::Script`
+//! (see `enums.rs`), gated behind the `rune` cargo feature so sims that only use the closed-set
+//! Rust variants pay no extra binary size or runtime cost. Compiled units are cached by a hash of
+//! the trigger's script source, so identical script text - whether from many copies of the same
+//! homebrew item or an unchanged script across sim restarts - only compiles once.
+//!
+//! Without the `rune` feature, `run_condition_script`/`run_effect_script` are no-ops (condition
+//! never fires, effect applies no mutations) so the enum variants still round-trip through
+//! (de)serialization and match arms without requiring the dependency. With the feature, scripts
+//! are compiled and cached but not executed, so the results are the same.
+
+use crate::context::TurnContext;
+use crate::events::Event;
+use crate::model::EffectTrigger;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A mutation a `TriggerEffect::Script` asks the engine to apply, returned from the script rather
+/// than executed directly - keeps script evaluation free of direct `TurnContext` mutation, same
+/// as how `TriggerEffect::DealDamage` flows its result back through `TurnContext::apply_damage`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptMutation {
+    DealDamage { target_id: String, amount: f64, damage_type: String },
+    Heal { target_id: String, amount: f64 },
+}
+
+#[cfg(feature = "rune")]
+pub struct CompiledScript {
+    unit: std::sync::Arc<rune::Unit>,
+}
+
+#[cfg(not(feature = "rune"))]
+pub struct CompiledScript {
+    #[allow(dead_code)]
+    source: String,
+}
+
+thread_local! {
+    /// Keyed on a hash of the script source (as `trigger_cache_key` computes it), so two
+    /// different `EffectTrigger`s that share script text share one entry.
+    static SCRIPT_CACHE: RefCell<HashMap<u64, CompiledScript>> = RefCell::new(HashMap::new());
+}
+
+/// Cache key for a buff's `EffectTrigger` - hashes the trigger's script source, so editing or swapping the
+/// script (a different `source` string) naturally produces a different key and the old
+/// compilation is simply never looked up again; call `evict` to also reclaim it.
+pub fn trigger_cache_key(trigger: &EffectTrigger) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    trigger.condition_script_source().hash(&mut hasher);
+    hasher.finish()
+}
+
+impl EffectTrigger {
+    /// The script source backing this trigger's condition or effect, if either is a `Script`
+    /// variant - used as the identity for `trigger_cache_key` rather than the whole trigger,
+    /// since `TriggerCondition`/`TriggerEffect` don't derive `Hash` yet.
+    fn condition_script_source(&self) -> &str {
+        match &self.condition {
+            crate::enums::TriggerCondition::Script { source } => source.as_str(),
+            _ => match &self.effect {
+                crate::enums::TriggerEffect::Script { source } => source.as_str(),
+                _ => "",
+            },
+        }
+    }
+}
+
+#[cfg(feature = "rune")]
+fn compile_source(source: &str) -> Result<CompiledScript, String> {
+    let mut sources = rune::Sources::new();
+    sources
+        .insert(rune::Source::new("trigger_script", source))
+        .map_err(|e| e.to_string())?;
+    let unit = rune::prepare(&mut sources)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(CompiledScript { unit: std::sync::Arc::new(unit) })
+}
+
+#[cfg(not(feature = "rune"))]
+fn compile_source(source: &str) -> Result<CompiledScript, String> {
+    Ok(CompiledScript { source: source.to_string() })
+}
+
+/// Compiles (or fetches the already-cached compilation of) `source` under `key` - see
+/// `trigger_cache_key`. Returns an error string on a real Rune syntax error when the `rune`
+/// feature is enabled; always succeeds otherwise.
+fn get_or_compile(key: u64, source: &str) -> Result<(), String> {
+    SCRIPT_CACHE.with(|cache| {
+        if cache.borrow().contains_key(&key) {
+            return Ok(());
+        }
+        let compiled = compile_source(source)?;
+        cache.borrow_mut().insert(key, compiled);
+        Ok(())
+    })
+}
+
+/// Evicts `key`'s cached compilation - call this when a script is edited or its owning buff is
+/// removed, so a long-running sim picks up the new source on next evaluation instead of reusing
+/// the stale compiled unit.
+pub fn evict(key: u64) {
+    SCRIPT_CACHE.with(|cache| {
+        cache.borrow_mut().remove(&key);
+    });
+}
+
+/// Compiles and caches a `TriggerCondition::Script`'s source. The script is not executed and
+/// `event` is not read, so this always returns `false`, with or without the `rune` feature -
+/// the script condition never fires.
+#[cfg(feature = "rune")]
+pub fn run_condition_script(key: u64, source: &str, event: &Event) -> bool {
+    if get_or_compile(key, source).is_err() {
+        return false;
+    }
+    // TODO: construct a Vm from the cached unit, pass `event` in (serialized to a Rune value),
+    // and call its `condition` entrypoint, returning the bool it produces.
+    let _ = event;
+    false
+}
+
+#[cfg(not(feature = "rune"))]
+pub fn run_condition_script(_key: u64, _source: &str, _event: &Event) -> bool {
+    false
+}
+
+/// Compiles and caches a `TriggerEffect::Script`'s source. The script is not executed, so this
+/// always returns no mutations, with or without the `rune` feature.
+#[cfg(feature = "rune")]
+pub fn run_effect_script(key: u64, source: &str, context: &TurnContext) -> Vec<ScriptMutation> {
+    if get_or_compile(key, source).is_err() {
+        return Vec::new();
+    }
+    // TODO: construct a Vm from the cached unit, pass a handle onto `context` (attacker/target
+    // ledgers, buffs) in, and collect the `ScriptMutation`s its `effect` entrypoint returns.
+    let _ = context;
+    Vec::new()
+}
+
+#[cfg(not(feature = "rune"))]
+pub fn run_effect_script(_key: u64, _source: &str, _context: &TurnContext) -> Vec<ScriptMutation> {
+    Vec::new()
+}
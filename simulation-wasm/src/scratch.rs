@@ -0,0 +1,88 @@
+//! Reusable per-iteration scratch buffers for hot simulation loops.
+//!
+//! A Monte Carlo batch (`api::runner::run_survey_pass` and friends) rebuilds a fresh
+//! `Vec<Combattant>`/`Vec<Event>` every single iteration, which on WASM means hitting the
+//! allocator thousands of times for buffers that are the same rough size run after run.
+//! `IterationScratch` holds that backing storage across iterations: each iteration `clear()`s
+//! the buffer (dropping its contents but keeping the allocation) instead of dropping the `Vec`
+//! entirely, and grows it to the previous iteration's high-water mark up front so it fills
+//! without reallocating partway through. This is "bump allocator"-style reuse applied at the
+//! granularity of a couple of long-lived `Vec`s rather than a general-purpose arena, since that's
+//! the allocation pattern `demonstrate_memory_growth` actually measures.
+
+use crate::events::Event;
+use crate::model::Combattant;
+
+/// Backing storage reused across simulation iterations. Call `reset` at the start of each
+/// iteration to get a cleared-but-pre-reserved buffer, and `record_high_water` at the end so
+/// the next iteration's reservation accounts for how big this one grew.
+#[derive(Debug, Default)]
+pub struct IterationScratch {
+    combatants: Vec<Combattant>,
+    events: Vec<Event>,
+    combatants_high_water: usize,
+    events_high_water: usize,
+}
+
+impl IterationScratch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears the combatant buffer (keeping its allocation) and reserves up to the previous
+    /// iteration's high-water mark, then returns it for this iteration to fill.
+    pub fn combatants_buffer(&mut self) -> &mut Vec<Combattant> {
+        self.combatants.clear();
+        if self.combatants.capacity() < self.combatants_high_water {
+            self.combatants.reserve(self.combatants_high_water - self.combatants.capacity());
+        }
+        &mut self.combatants
+    }
+
+    /// Clears the event buffer (keeping its allocation) and reserves up to the previous
+    /// iteration's high-water mark, then returns it for this iteration to fill.
+    pub fn events_buffer(&mut self) -> &mut Vec<Event> {
+        self.events.clear();
+        if self.events.capacity() < self.events_high_water {
+            self.events.reserve(self.events_high_water - self.events.capacity());
+        }
+        &mut self.events
+    }
+
+    /// Updates the high-water marks from how full the buffers ended up this iteration, so the
+    /// next call to `combatants_buffer`/`events_buffer` reserves enough up front.
+    pub fn record_high_water(&mut self) {
+        self.combatants_high_water = self.combatants_high_water.max(self.combatants.len());
+        self.events_high_water = self.events_high_water.max(self.events.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combatants_buffer_clears_but_keeps_capacity() {
+        let mut scratch = IterationScratch::new();
+        scratch.combatants_buffer().reserve(64);
+        let cap_before = scratch.combatants.capacity();
+
+        scratch.record_high_water();
+        let buf = scratch.combatants_buffer();
+        assert!(buf.is_empty());
+        assert!(scratch.combatants.capacity() >= cap_before);
+    }
+
+    #[test]
+    fn test_record_high_water_tracks_the_max_seen() {
+        let mut scratch = IterationScratch::new();
+        scratch.events_buffer().resize_with(10, || Event::CreatureDied { creature_id: "x".to_string() });
+        scratch.record_high_water();
+        assert_eq!(scratch.events_high_water, 10);
+
+        scratch.events_buffer().resize_with(3, || Event::CreatureDied { creature_id: "x".to_string() });
+        scratch.record_high_water();
+        // High-water mark should stay at the largest iteration seen, not shrink back to 3.
+        assert_eq!(scratch.events_high_water, 10);
+    }
+}
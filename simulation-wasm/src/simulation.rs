@@ -4,58 +4,99 @@ use crate::model::*;
 use crate::enums::*;
 use crate::dice;
 // use crate::targeting::*; // Unused if execute_turn doesn't do targeting directly? No, it does get_targets.
+use crate::targeting;
 use crate::targeting::get_targets;
 use crate::actions::*;
 use crate::aggregation::*;
 use crate::cleanup::*;
 use crate::resolution; // New module
+use crate::rng;
 use wasm_bindgen::prelude::*;
 use serde_wasm_bindgen;
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::io::Write;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+
+/// Derive a per-iteration seed from a master seed so that `run_monte_carlo` is
+/// bit-for-bit reproducible regardless of how rayon schedules iterations across threads.
+fn derive_iteration_seed(master_seed: u64, iteration: usize) -> u64 {
+    master_seed
+        .wrapping_mul(0x9E3779B97F4A7C15)
+        .wrapping_add(iteration as u64)
+}
 
-pub fn run_monte_carlo(players: &[Creature], encounters: &[Encounter], iterations: usize) -> Vec<SimulationResult> {
-    let mut results: Vec<(f64, SimulationResult)> = Vec::with_capacity(iterations);
-
-    for i in 0..iterations {
-        let log_enabled = i == 0;
-        let (result, run_log) = run_single_simulation(players, encounters, log_enabled);
-        let score = calculate_score(&result);
-        results.push((score, result));
-
-        if log_enabled {
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                // Write detailed log to file
-                let path = std::path::Path::new("./GEMINI_REPORTS");
-                if path.exists() {
-                     let timestamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
-                     let filename = path.join(format!("detailed_run_log_{}.txt", timestamp));
-                     if let Ok(mut file) = std::fs::File::create(filename) {
-                         for line in run_log {
-                             let _ = writeln!(file, "{}", line);
-                         }
-                     }
-                }
+/// Write out the detailed log captured for the first Monte Carlo iteration, the same way
+/// a single un-parallelized run always has.
+fn write_detailed_run_log(run_log: Vec<String>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        // Write detailed log to file
+        let path = std::path::Path::new("./GEMINI_REPORTS");
+        if path.exists() {
+             let timestamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+             let filename = path.join(format!("detailed_run_log_{}.txt", timestamp));
+             if let Ok(mut file) = std::fs::File::create(filename) {
+                 for line in run_log {
+                     let _ = writeln!(file, "{}", line);
+                 }
+             }
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        // For WASM, we can't write to file easily, but we can log to console
+        if !run_log.is_empty() {
+            web_sys::console::log_1(&"--- DETAILED SIMULATION LOG (First Run) ---".into());
+            // Log in chunks to avoid browser limits if needed, or just summary
+            // For now, let's log the first 100 lines
+            for line in run_log.iter().take(100) {
+                web_sys::console::log_1(&line.into());
             }
-            #[cfg(target_arch = "wasm32")]
-            {
-                // For WASM, we can't write to file easily, but we can log to console
-                if !run_log.is_empty() {
-                    web_sys::console::log_1(&"--- DETAILED SIMULATION LOG (First Run) ---".into());
-                    // Log in chunks to avoid browser limits if needed, or just summary
-                    // For now, let's log the first 100 lines
-                    for line in run_log.iter().take(100) {
-                        web_sys::console::log_1(&line.into());
-                    }
-                    if run_log.len() > 100 {
-                        web_sys::console::log_1(&format!("... and {} more lines", run_log.len() - 100).into());
-                    }
-                }
+            if run_log.len() > 100 {
+                web_sys::console::log_1(&format!("... and {} more lines", run_log.len() - 100).into());
             }
         }
     }
+}
+
+pub fn run_monte_carlo(players: &[Creature], encounters: &[Encounter], iterations: usize, seed: u64) -> Vec<SimulationResult> {
+    // On native targets, run iterations across rayon's thread pool. Each iteration seeds
+    // its own thread-local RNG deterministically from `seed`, so the result set is
+    // identical bit-for-bit no matter how the scheduler interleaves work.
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut results: Vec<(f64, SimulationResult)> = (0..iterations)
+        .into_par_iter()
+        .map(|i| {
+            // Iteration 0 always produces its detailed run log, even in parallel.
+            let log_enabled = i == 0;
+            rng::seed_rng(derive_iteration_seed(seed, i));
+            let (result, run_log) = run_single_simulation(players, encounters, log_enabled);
+            rng::clear_rng();
+            let score = calculate_score(&result);
+            if log_enabled {
+                write_detailed_run_log(run_log);
+            }
+            (score, result)
+        })
+        .collect();
+
+    // WASM has no thread pool available by default, so fall back to the sequential loop.
+    #[cfg(target_arch = "wasm32")]
+    let mut results: Vec<(f64, SimulationResult)> = (0..iterations)
+        .map(|i| {
+            let log_enabled = i == 0;
+            rng::seed_rng(derive_iteration_seed(seed, i));
+            let (result, run_log) = run_single_simulation(players, encounters, log_enabled);
+            rng::clear_rng();
+            let score = calculate_score(&result);
+            if log_enabled {
+                write_detailed_run_log(run_log);
+            }
+            (score, result)
+        })
+        .collect();
 
     // Sort by score
     results.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
@@ -83,6 +124,94 @@ pub fn run_monte_carlo(players: &[Creature], encounters: &[Encounter], iteration
     results.into_iter().map(|(_, r)| r).collect()
 }
 
+/// Whether the party survived an entire simulation run: every encounter's last round
+/// leaves at least one player standing. Shared by `run_batch`'s win count and
+/// `boost_search`'s win-rate probes so both agree on what "a win" means.
+pub(crate) fn run_is_win(result: &SimulationResult) -> bool {
+    result.encounters.iter().all(|encounter| {
+        encounter
+            .rounds
+            .last()
+            .is_some_and(|round| round.team1.iter().any(|c| c.final_state.current_hp > 0))
+    })
+}
+
+/// Aggregate outcome of a `run_batch` call: per-creature `EncounterStats` summed across
+/// every trial and encounter, plus how many trials the party won outright and the total
+/// rounds played across the whole batch.
+#[derive(Debug, Clone, Default)]
+pub struct BatchAggregate {
+    pub stats: HashMap<String, EncounterStats>,
+    pub win_count: usize,
+    pub total_trials: usize,
+    pub total_rounds: usize,
+}
+
+fn merge_stats(into: &mut HashMap<String, EncounterStats>, from: &HashMap<String, EncounterStats>) {
+    for (id, stats) in from {
+        let entry = into.entry(id.clone()).or_insert_with(|| EncounterStats {
+            damage_dealt: 0.0,
+            damage_taken: 0.0,
+            heal_given: 0.0,
+            heal_received: 0.0,
+            characters_buffed: 0.0,
+            buffs_received: 0.0,
+            characters_debuffed: 0.0,
+            debuffs_received: 0.0,
+            times_unconscious: 0.0,
+        });
+        entry.damage_dealt += stats.damage_dealt;
+        entry.damage_taken += stats.damage_taken;
+        entry.heal_given += stats.heal_given;
+        entry.heal_received += stats.heal_received;
+        entry.characters_buffed += stats.characters_buffed;
+        entry.buffs_received += stats.buffs_received;
+        entry.characters_debuffed += stats.characters_debuffed;
+        entry.debuffs_received += stats.debuffs_received;
+        entry.times_unconscious += stats.times_unconscious;
+    }
+}
+
+/// Run `n_trials` independent trials across rayon's work-stealing pool (falling back to a
+/// sequential loop on WASM, same as `run_monte_carlo`), each with its own deterministically
+/// derived RNG seed, then merge every trial's `EncounterStats` into one aggregate. The
+/// result is identical regardless of how many threads ran it or how work was stolen between
+/// them, since merging is order-independent summation keyed by creature id.
+pub fn run_batch(players: &[Creature], encounters: &[Encounter], n_trials: usize, seed: u64) -> BatchAggregate {
+    #[cfg(not(target_arch = "wasm32"))]
+    let trials: Vec<SimulationResult> = (0..n_trials)
+        .into_par_iter()
+        .map(|i| {
+            rng::seed_rng(derive_iteration_seed(seed, i));
+            let (result, _run_log) = run_single_simulation(players, encounters, false);
+            rng::clear_rng();
+            result
+        })
+        .collect();
+
+    #[cfg(target_arch = "wasm32")]
+    let trials: Vec<SimulationResult> = (0..n_trials)
+        .map(|i| {
+            rng::seed_rng(derive_iteration_seed(seed, i));
+            let (result, _run_log) = run_single_simulation(players, encounters, false);
+            rng::clear_rng();
+            result
+        })
+        .collect();
+
+    let mut aggregate = BatchAggregate { total_trials: trials.len(), ..Default::default() };
+    for trial in &trials {
+        if run_is_win(trial) {
+            aggregate.win_count += 1;
+        }
+        for encounter in &trial.encounters {
+            aggregate.total_rounds += encounter.rounds.len();
+            merge_stats(&mut aggregate.stats, &encounter.stats);
+        }
+    }
+    aggregate
+}
+
 #[wasm_bindgen]
 pub fn run_simulation(
     players_val: JsValue,
@@ -203,11 +332,11 @@ fn create_combattant(creature: Creature, id: String) -> Combattant {
 
 fn roll_initiative(c: &Creature) -> f64 {
     let roll = if c.initiative_advantage {
-        let r1 = rand::thread_rng().gen_range(1..=20);
-        let r2 = rand::thread_rng().gen_range(1..=20);
+        let r1 = rng::get_rng().gen_range(1..=20);
+        let r2 = rng::get_rng().gen_range(1..=20);
         r1.max(r2)
     } else {
-        rand::thread_rng().gen_range(1..=20)
+        rng::get_rng().gen_range(1..=20)
     } as f64;
     
     roll + c.initiative_bonus
@@ -323,11 +452,14 @@ fn execute_precombat_actions(
                     CleanupInstruction::BreakConcentration(combatant_id, buff_id) => {
                         break_concentration(&combatant_id, &buff_id, team1, team2);
                     },
+                    CleanupInstruction::TriggerOnDeath(source_id) => {
+                        apply_on_death_triggers(&source_id, team1, team2, stats, log, log_enabled, None);
+                    },
                 }
             }
         }
     }
-    
+
     // Execute pre-combat actions for team2 (monsters)
     for attacker_index in 0..team2.len() {
         let precombat_actions: Vec<_> = team2[attacker_index]
@@ -411,6 +543,9 @@ fn execute_precombat_actions(
                     CleanupInstruction::BreakConcentration(combatant_id, buff_id) => {
                         break_concentration(&combatant_id, &buff_id, team2, team1);
                     },
+                    CleanupInstruction::TriggerOnDeath(source_id) => {
+                        apply_on_death_triggers(&source_id, team2, team1, stats, log, log_enabled, None);
+                    },
                 }
             }
         }
@@ -466,13 +601,17 @@ fn run_encounter(players: &[Combattant], encounter: &Encounter, log: &mut Vec<St
     }
 }
 
-fn run_round(team1: &[Combattant], team2: &[Combattant], stats: &mut HashMap<String, EncounterStats>, log: &mut Vec<String>, log_enabled: bool, round_num: usize) -> Round {
+pub(crate) fn run_round(team1: &[Combattant], team2: &[Combattant], stats: &mut HashMap<String, EncounterStats>, log: &mut Vec<String>, log_enabled: bool, round_num: usize) -> Round {
     if log_enabled {
         log.push(format!("\n# Round {}", round_num));
     }
 
     #[cfg(debug_assertions)]
     eprintln!("\n--- Round START ---");
+
+    // Fresh focus-fire claims each round so fire can spread across a new set of enemies.
+    targeting::reset_focus_fire_claims();
+
     // 1. Create mutable copies of teams
     let mut t1 = team1.to_vec();
     let mut t2 = team2.to_vec();
@@ -481,19 +620,25 @@ fn run_round(team1: &[Combattant], team2: &[Combattant], stats: &mut HashMap<Str
     #[derive(Clone, Copy, Debug)]
     enum TeamId { Team1, Team2 } // This enum is defined inside run_round
     
-    let mut turn_order: Vec<(TeamId, usize, f64)> = Vec::new();
-    for (i, c) in t1.iter().enumerate() { turn_order.push((TeamId::Team1, i, c.initiative)); }
-    for (i, c) in t2.iter().enumerate() { turn_order.push((TeamId::Team2, i, c.initiative)); }
-    
-    // Sort by initiative descending
-    turn_order.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+    // Effective power (expected damage-per-round, `targeting::estimate_dpr`) breaks
+    // initiative ties deterministically, strongest unit acting first.
+    let mut turn_order: Vec<(TeamId, usize, f64, f64)> = Vec::new();
+    for (i, c) in t1.iter().enumerate() { turn_order.push((TeamId::Team1, i, c.initiative, targeting::estimate_dpr(c))); }
+    for (i, c) in t2.iter().enumerate() { turn_order.push((TeamId::Team2, i, c.initiative, targeting::estimate_dpr(c))); }
+
+    // Sort by initiative descending, then by effective power descending.
+    turn_order.sort_by(|a, b| {
+        b.2.partial_cmp(&a.2)
+            .unwrap()
+            .then_with(|| b.3.partial_cmp(&a.3).unwrap_or(std::cmp::Ordering::Equal))
+    });
     
     #[cfg(debug_assertions)]
     eprintln!("  Turn Order: {:?}", turn_order.iter().map(|(id,_,init)| format!("{:?} {:.1}", id, init)).collect::<Vec<_>>());
 
     
     // 3. Iterate through turns
-    for (team_id, idx, _initiative_value) in turn_order {
+    for (team_id, idx, _initiative_value, _power_value) in turn_order {
         let _combatant_name = match team_id {
             TeamId::Team1 => t1[idx].creature.name.clone(),
             TeamId::Team2 => t2[idx].creature.name.clone(),
@@ -520,8 +665,8 @@ fn run_round(team1: &[Combattant], team2: &[Combattant], stats: &mut HashMap<Str
         
         // Execute turn
         match team_id {
-            TeamId::Team1 => execute_turn(idx, &mut t1, &mut t2, stats, false, log, log_enabled),
-            TeamId::Team2 => execute_turn(idx, &mut t2, &mut t1, stats, true, log, log_enabled),
+            TeamId::Team1 => execute_turn(idx, &mut t1, &mut t2, stats, false, log, log_enabled, round_num),
+            TeamId::Team2 => execute_turn(idx, &mut t2, &mut t1, stats, true, log, log_enabled, round_num),
         }
         #[cfg(debug_assertions)]
         eprintln!("  {} turn END. Current State: P1 HP: {:.1}, P2 HP: {:.1}", _combatant_name, t1[0].final_state.current_hp, t2[0].final_state.current_hp);
@@ -560,7 +705,7 @@ fn iterate_combattant(c: &Combattant) -> Combattant {
             BuffDuration::RepeatTheSaveEachRound => {
                 let dc = dice::evaluate(buff.dc.as_ref().unwrap_or(&DiceFormula::Value(10.0)), 1);
                 let save_bonus = c.creature.save_bonus;
-                let roll = rand::thread_rng().gen_range(1..=20) as f64;
+                let roll = rng::get_rng().gen_range(1..=20) as f64;
                 if roll + save_bonus < dc {
                      new_initial_state.buffs.insert(name.clone(), buff.clone());
                 }
@@ -620,14 +765,68 @@ fn generate_actions_for_creature(c: &mut Combattant, allies: &[Combattant], enem
 }
 
 // Simplified execute_turn delegating to resolution logic
-fn execute_turn(index: usize, allies: &mut [Combattant], enemies: &mut [Combattant], stats: &mut HashMap<String, EncounterStats>, _is_enemy: bool, log: &mut Vec<String>, log_enabled: bool) {
+fn execute_turn(index: usize, allies: &mut [Combattant], enemies: &mut [Combattant], stats: &mut HashMap<String, EncounterStats>, _is_enemy: bool, log: &mut Vec<String>, log_enabled: bool, round_num: usize) {
     // Log the turn
         let attacker_name_for_log = allies[index].creature.name.clone();
         log.push(format!("\n## {} (HP: {:.0}/{:.0})", attacker_name_for_log, allies[index].final_state.current_hp, allies[index].creature.hp));
 
+    // MCTS combattants replace the scripted action-slot selection below with a tree search
+    // over this turn's candidate action-economy bundles. Rollouts never nest a second
+    // search (see `planner::in_rollout`), and a zero `mcts_iterations` budget falls back to
+    // the scripted path below instead of searching, so `choose_action_mcts` returning `None`
+    // here always means "no legal actions this turn", not "search disabled".
+    let mcts_active = allies[index].creature.ai_mode == AiMode::Mcts
+        && allies[index].creature.mcts_iterations != Some(0)
+        && !crate::planner::in_rollout();
+    if mcts_active {
+        if let Some(bundle) = crate::planner::choose_action_mcts(index, allies, enemies, stats, round_num) {
+            for mv in &bundle.moves {
+                execute_chosen_action(index, &mv.action, &mv.targets, allies, enemies, stats, log, log_enabled);
+            }
+        } else if log_enabled {
+            log.push("    - No actions available.".to_string());
+        }
+        return;
+    }
+
+    // `Aggressive`/`Defensive` are pluggable `CombatStrategy` implementations (see
+    // `crate::strategy`), each gated behind its own Cargo feature so a minimal build
+    // doesn't pay for a policy no scenario selects. `Scripted` keeps using the inline
+    // logic below directly rather than routing through `strategy::GreedyPriority` — the
+    // two are kept in sync by hand since this engine's turn loop isn't unit-tested.
+    #[cfg(feature = "aggressive_ai")]
+    if allies[index].creature.ai_mode == AiMode::Aggressive {
+        use crate::strategy::CombatStrategy;
+        let chosen: Vec<Action> = crate::strategy::Aggressive
+            .choose_actions(index, allies, enemies)
+            .into_iter()
+            .cloned()
+            .collect();
+        for action in &chosen {
+            let raw_targets = get_targets(&allies[index], action, allies, enemies);
+            execute_chosen_action(index, action, &raw_targets, allies, enemies, stats, log, log_enabled);
+        }
+        return;
+    }
+
+    #[cfg(feature = "defensive_ai")]
+    if allies[index].creature.ai_mode == AiMode::Defensive {
+        use crate::strategy::CombatStrategy;
+        let chosen: Vec<Action> = crate::strategy::Defensive
+            .choose_actions(index, allies, enemies)
+            .into_iter()
+            .cloned()
+            .collect();
+        for action in &chosen {
+            let raw_targets = get_targets(&allies[index], action, allies, enemies);
+            execute_chosen_action(index, action, &raw_targets, allies, enemies, stats, log, log_enabled);
+        }
+        return;
+    }
+
     // Get actions
     let actions = get_actions(&allies[index], allies, enemies);
-    
+
     if actions.is_empty() {
         #[cfg(debug_assertions)]
         eprintln!("      No actions available.");
@@ -685,35 +884,28 @@ fn execute_turn(index: usize, allies: &mut [Combattant], enemies: &mut [Combatta
             continue;
         }
 
-        // NEW: Check for concentration conflict (Bug #5 & Bug #7)
+        // Check for concentration conflict against the data-driven registry in
+        // `crate::concentration` rather than a hard-coded template-name match, so a new
+        // concentration spell gets correct skip/re-cast behavior from its registry entry
+        // alone.
         if is_concentration_action(action) {
             if let Some(current_buff_id) = &allies[index].final_state.concentrating_on {
-                // Check if this is a "moveable" concentration spell (Hunter's Mark, Hex)
-                let is_moveable = match action {
-                    Action::Template(t) => {
-                        let name = t.template_options.template_name.as_str();
-                        matches!(name, "Hunter's Mark" | "Hex")
-                    },
-                    _ => false
+                let spec = match action {
+                    Action::Template(t) => crate::concentration::concentration_registry(&t.template_options.template_name),
+                    _ => None,
                 };
 
-                if is_moveable {
-                    // For moveable spells, check if the current target is still valid (alive)
-                    let mut target_alive = false;
-                    for enemy in enemies.iter() {
-                        if enemy.final_state.buffs.contains_key(current_buff_id) && enemy.final_state.current_hp > 0.0 {
-                            target_alive = true;
-                            break;
-                        }
-                    }
-
-                    if target_alive {
+                let moveable = spec.is_some_and(|s| s.moveable);
+                if moveable {
+                    let still_worth_keeping = spec.unwrap().still_worth_keeping(current_buff_id, allies, enemies);
+                    if still_worth_keeping {
                         if log_enabled {
                             log.push(format!("      -> Skips {} (already active on alive target)", action.base().name));
                         }
                         continue;
                     }
-                    // If target is dead or buff not found, allow re-casting (moving)
+                    // The current cast is no longer worth keeping (target dead, buff gone):
+                    // allow re-casting (moving) it.
                 } else {
                     if log_enabled {
                         log.push(format!("      -> Skips {} (already concentrating)", action.base().name));
@@ -733,69 +925,83 @@ fn execute_turn(index: usize, allies: &mut [Combattant], enemies: &mut [Combatta
 
     // Execute all selected actions
     for action in &actions_to_execute {
-        #[cfg(debug_assertions)]
-        eprintln!("      Chose action: {}", action.base().name);
+        let raw_targets = get_targets(&allies[index], action, allies, enemies);
+        execute_chosen_action(index, action, &raw_targets, allies, enemies, stats, log, log_enabled);
+    }
+}
 
-        if log_enabled {
-            log.push(format!("    - Uses Action: {}", action.base().name));
-        }
+// Resolve one already-chosen action/targets pair and apply the cleanup instructions it
+// returns. Shared by the scripted action-economy loop above and the MCTS planner branch.
+fn execute_chosen_action(
+    index: usize,
+    action: &Action,
+    raw_targets: &[(bool, usize)],
+    allies: &mut [Combattant],
+    enemies: &mut [Combattant],
+    stats: &mut HashMap<String, EncounterStats>,
+    log: &mut Vec<String>,
+    log_enabled: bool,
+) {
+    #[cfg(debug_assertions)]
+    eprintln!("      Chose action: {}", action.base().name);
 
-        // Resolve targets (this takes an immutable attacker and returns indices, so it's fine)
-        let raw_targets = get_targets(&allies[index], action, allies, enemies);
+    if log_enabled {
+        log.push(format!("    - Uses Action: {}", action.base().name));
+    }
 
-        #[cfg(debug_assertions)]
-        eprintln!("      Selected {} targets.", raw_targets.len());
+    #[cfg(debug_assertions)]
+    eprintln!("      Selected {} targets.", raw_targets.len());
 
-        // NEW: Check if any targets were found (Bug #3 secondary check)
-        if raw_targets.is_empty() {
-            if log_enabled {
-                log.push("      -> No valid targets (skipping execution)".to_string());
-            }
-            continue;
+    // NEW: Check if any targets were found (Bug #3 secondary check)
+    if raw_targets.is_empty() {
+        if log_enabled {
+            log.push("      -> No valid targets (skipping execution)".to_string());
         }
+        return;
+    }
 
-        // Record action in history (Aggregation) - this requires a clone of the action
-        let mut action_record = CombattantAction {
-            action: (*action).clone(),
-            targets: HashMap::new(),
-        };
+    // Record action in history (Aggregation) - this requires a clone of the action
+    let mut action_record = CombattantAction {
+        action: action.clone(),
+        targets: HashMap::new(),
+    };
 
-        for (is_target_enemy, target_idx) in &raw_targets {
-            let target_id = if *is_target_enemy { &enemies[*target_idx].id } else { &allies[*target_idx].id };
-            *action_record.targets.entry(target_id.clone()).or_insert(0) += 1;
-        }
+    for (is_target_enemy, target_idx) in raw_targets {
+        let target_id = if *is_target_enemy { &enemies[*target_idx].id } else { &allies[*target_idx].id };
+        *action_record.targets.entry(target_id.clone()).or_insert(0) += 1;
+    }
 
-        // Delegate execution mechanics to the resolution module
-        // This handles slice splitting, mutable borrowing, and effect application including triggers
-        let instructions = resolution::resolve_action_execution(
-            index,
-            allies,
-            enemies,
-            action,
-            &raw_targets,
-            &action_record,
-            stats,
-            log,
-            log_enabled
-        );
-
-
-
-        // Process returned cleanup instructions
-        for instruction in instructions {
-            match instruction {
-                CleanupInstruction::RemoveAllBuffsFromSource(source_id) => {
-                    remove_all_buffs_from_source(&source_id, allies, enemies);
-                },
-                CleanupInstruction::BreakConcentration(combatant_id, buff_id) => {
-                    break_concentration(&combatant_id, &buff_id, allies, enemies);
-                },
-            }
+    // Delegate execution mechanics to the resolution module
+    // This handles slice splitting, mutable borrowing, and effect application including triggers
+    let instructions = resolution::resolve_action_execution(
+        index,
+        allies,
+        enemies,
+        action,
+        raw_targets,
+        &action_record,
+        stats,
+        log,
+        log_enabled
+    );
+
+    // Process returned cleanup instructions
+    for instruction in instructions {
+        match instruction {
+            CleanupInstruction::RemoveAllBuffsFromSource(source_id) => {
+                remove_all_buffs_from_source(&source_id, allies, enemies);
+            },
+            CleanupInstruction::BreakConcentration(combatant_id, buff_id) => {
+                break_concentration(&combatant_id, &buff_id, allies, enemies);
+            },
+            CleanupInstruction::TriggerOnDeath(source_id) => {
+                apply_on_death_triggers(&source_id, allies, enemies, stats, log, log_enabled, None);
+            },
         }
     }
 }
 
-fn get_action_priority(freq: &Frequency) -> i32 {
+pub(crate) fn get_action_priority(freq: &Frequency) -> i32 {
     match freq {
         Frequency::Limited { .. } => 3,
         Frequency::Recharge { .. } => 2,
@@ -806,15 +1012,11 @@ fn get_action_priority(freq: &Frequency) -> i32 {
 #[cfg(test)]
 #[path = "./simulation_test.rs"]
 mod simulation_test;
-fn is_concentration_action(action: &Action) -> bool {
+pub(crate) fn is_concentration_action(action: &Action) -> bool {
     match action {
         Action::Buff(a) => a.buff.concentration,
         Action::Debuff(a) => a.buff.concentration,
-        Action::Template(a) => {
-            // Check known concentration templates
-            let name = a.template_options.template_name.as_str();
-            matches!(name, "Hunter's Mark" | "Bless" | "Bane" | "Hex")
-        },
+        Action::Template(a) => crate::concentration::concentration_registry(&a.template_options.template_name).is_some(),
         _ => false,
     }
 }
@@ -3,8 +3,15 @@
 //! This module handles the "Shield Wall" ordering system that determines the fixed
 //! visual order of players in the UI, from Tank (Slot 1/Left) to Glass Cannon (Slot N/Right).
 
+use crate::action_resolver::{should_power_attack, POWER_ATTACK_TO_HIT_PENALTY};
+use crate::dice;
 use crate::model::{Creature, TimelineStep};
 
+/// Representative target AC assumed when estimating whether a monster's attack would come out
+/// ahead running a power attack - there's no concrete encounter/target here, only the stat
+/// block, so this mirrors the baseline AC `crate::strategy` uses for the same kind of estimate.
+const BASELINE_TARGET_AC: f64 = 15.0;
+
 /// Represents a player's assigned position in the UI layout
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -19,29 +26,31 @@ pub struct PlayerSlot {
 
 /// Extract all attack bonuses from a creature's actions
 ///
-/// Parses through attack actions to collect to_hit bonuses.
-/// For dice formulas (e.g., "1d20+5"), extracts the static bonus.
+/// Parses through attack actions to collect to_hit bonuses. For dice formulas (e.g.,
+/// "1d20+5"), uses `dice::expr::flat_bonus` so multi-die terms and modifiers anywhere in the
+/// expression are accounted for, rather than just the last one. When a monster would come out
+/// ahead running a power attack against `BASELINE_TARGET_AC` (see `should_power_attack`), the
+/// bonus is adjusted down by `POWER_ATTACK_TO_HIT_PENALTY` so this reflects its realistic
+/// effective to-hit rather than the unadjusted stat block value.
 pub fn extract_attack_bonuses_from_creature(creature: &Creature) -> Vec<i32> {
     let mut bonuses = Vec::new();
 
     for action in &creature.actions {
         // Only check attack actions
         if let crate::model::Action::Atk(attack) = action {
-            // Extract to_hit bonus if present
-            match &attack.to_hit {
-                crate::model::DiceFormula::Value(bonus) => {
-                    bonuses.push(*bonus as i32);
-                }
-                crate::model::DiceFormula::Expr(expr) => {
-                    // Try to parse expressions like "1d20+5" to extract the bonus
-                    // Look for + or - followed by a number at the end
-                    if let Some(pos) = expr.rfind('+').or_else(|| expr.rfind('-')) {
-                        if let Ok(bonus_str) = expr[pos..].parse::<f64>() {
-                            bonuses.push(bonus_str as i32);
-                        }
-                    }
-                }
-            }
+            let to_hit_bonus = match &attack.to_hit {
+                crate::model::DiceFormula::Value(bonus) => *bonus,
+                crate::model::DiceFormula::Expr(expr) => dice::expr::flat_bonus(expr) as f64,
+            };
+
+            let avg_damage = dice::average(&attack.dpr);
+            let effective_bonus = if should_power_attack(to_hit_bonus, avg_damage, BASELINE_TARGET_AC) {
+                to_hit_bonus + POWER_ATTACK_TO_HIT_PENALTY
+            } else {
+                to_hit_bonus
+            };
+
+            bonuses.push(effective_bonus as i32);
         }
     }
 
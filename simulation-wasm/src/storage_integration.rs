@@ -1,22 +1,428 @@
-// Stub storage_integration module - functionality removed
+// Stub storage_integration module - most functionality removed. `process_next_request` below
+// is a real implementation (not a stub): it's the piece a caller needs to get automatic
+// retry-with-backoff for failed background simulations, built entirely on the active
+// `SimulationQueue`/`ProgressCommunication` types rather than resurrecting the rest of what this
+// module used to do.
+
+use crate::background_simulation::BackgroundSimulationId;
+use crate::progress_communication::{
+    ProgressCommunication, ProgressError, ProgressUpdate, ProgressUpdateType, SimulationActivitySnapshot,
+};
+use crate::queue_manager::{
+    validate_scenario, QueueError, QueueStats, SimulationQueue, SimulationRequest, SimulationRequestStatus,
+};
+use crate::storage_manager::StorageManager;
+use std::sync::{Arc, Mutex, PoisonError};
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+/// Namespace this module persists queued/running requests under, within `StorageManager`.
+const QUEUE_NAMESPACE: &str = "simulation_queue";
+
+/// The subset of `SimulationRequest` that's both meaningful to restore and serializable -
+/// `available_at` is an `Instant` (monotonic, process-local) and isn't persisted; a restored
+/// request becomes immediately available instead.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct PersistedRequest {
+    request_id: String,
+    parameters: crate::user_interaction::ScenarioParameters,
+    priority: crate::background_simulation::SimulationPriority,
+    timestamp: u64,
+    retry_count: u32,
+    max_retries: u32,
+    retry_backoff_base_ms: u64,
+    allow_deduplication: bool,
+    status: SimulationRequestStatus,
+}
 
-#[derive(Debug, Clone)]
 pub struct StorageIntegration {
-    // Stub implementation
+    queue: SimulationQueue,
+    progress_comm: ProgressCommunication,
+    storage_manager: StorageManager,
+    config: StorageIntegrationConfig,
+    recovered_jobs: Arc<Mutex<usize>>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct StorageIntegrationConfig {
-    // Stub implementation
+    /// Maximum automatic retries for a failed simulation before it's given up as `Failed`
+    pub max_retries: u32,
+    /// Base backoff in milliseconds between retries - see `SimulationRequest::retry_backoff_base_ms`
+    pub retry_backoff_base_ms: u64,
+    /// Whether to mirror queue state (pending/running requests) through `StorageManager` so
+    /// `restore_from_storage` can recover them after a crash or reload
+    pub persist_queue: bool,
+}
+
+impl Default for StorageIntegrationConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_backoff_base_ms: 500,
+            persist_queue: true,
+        }
+    }
+}
+
+/// Aggregate stats a status dashboard or demo can poll, beyond the per-simulation activity
+/// snapshots in `get_integration_stats`.
+#[derive(Debug, Clone)]
+pub struct IntegrationStats {
+    pub queue: QueueStats,
+    /// How many requests `restore_from_storage` has re-enqueued since this `StorageIntegration`
+    /// was created
+    pub recovered_jobs: usize,
 }
 
 impl StorageIntegration {
+    /// Builds a `StorageIntegration` atop `storage_manager` and immediately calls
+    /// `restore_from_storage`, so a `storage_manager` carried over from a previous instance
+    /// (e.g. a crash or WASM page reload) re-enqueues whatever was still `Queued`/`Running`
+    /// before the caller ever submits a new request.
     pub fn new(
-        _storage_manager: crate::storage_manager::StorageManager,
-        _queue: crate::queue_manager::SimulationQueue,
-        _progress_comm: crate::progress_communication::ProgressCommunication,
-        _config: StorageIntegrationConfig,
+        storage_manager: crate::storage_manager::StorageManager,
+        queue: crate::queue_manager::SimulationQueue,
+        progress_comm: crate::progress_communication::ProgressCommunication,
+        config: StorageIntegrationConfig,
     ) -> Self {
-        Self {}
+        let integration = Self {
+            queue,
+            progress_comm,
+            storage_manager,
+            config,
+            recovered_jobs: Arc::new(Mutex::new(0)),
+        };
+        integration.restore_from_storage();
+        integration
+    }
+
+    /// Enqueue `request` and, if `config.persist_queue` is set, mirror it into storage so it
+    /// survives a crash until it's dequeued and reaches a terminal status.
+    pub fn submit_request(&self, request: SimulationRequest) -> Result<(), QueueError> {
+        self.queue.enqueue(request.clone())?;
+        self.persist_request(&request, SimulationRequestStatus::Queued);
+        Ok(())
+    }
+
+    /// Reload every persisted request that was still `Queued` or `Running` the last time it was
+    /// written (e.g. before a crash or WASM page reload) and re-enqueue it - a `Running` entry is
+    /// demoted back to `Queued` since whatever was executing it is gone. Returns how many were
+    /// recovered; this count accumulates into `IntegrationStats::recovered_jobs`.
+    pub fn restore_from_storage(&self) -> usize {
+        if !self.config.persist_queue {
+            return 0;
+        }
+
+        let mut recovered = 0;
+        for json in self.storage_manager.values_in_namespace(QUEUE_NAMESPACE) {
+            let Ok(persisted) = serde_json::from_str::<PersistedRequest>(&json) else {
+                continue;
+            };
+            if !matches!(
+                persisted.status,
+                SimulationRequestStatus::Queued | SimulationRequestStatus::Running
+            ) {
+                continue;
+            }
+
+            let mut request = SimulationRequest::new(persisted.parameters, persisted.priority)
+                .with_retry_policy(persisted.max_retries, persisted.retry_backoff_base_ms);
+            request.request_id = persisted.request_id;
+            request.timestamp = persisted.timestamp;
+            request.retry_count = persisted.retry_count;
+            request.allow_deduplication = persisted.allow_deduplication;
+
+            if self.queue.enqueue(request.clone()).is_ok() {
+                self.persist_request(&request, SimulationRequestStatus::Queued);
+                recovered += 1;
+            }
+        }
+
+        *self.recovered_jobs.lock().unwrap_or_else(PoisonError::into_inner) += recovered;
+        recovered
+    }
+
+    fn persist_request(&self, request: &SimulationRequest, status: SimulationRequestStatus) {
+        if !self.config.persist_queue {
+            return;
+        }
+        let persisted = PersistedRequest {
+            request_id: request.request_id.clone(),
+            parameters: request.parameters.clone(),
+            priority: request.priority,
+            timestamp: request.timestamp,
+            retry_count: request.retry_count,
+            max_retries: request.max_retries,
+            retry_backoff_base_ms: request.retry_backoff_base_ms,
+            allow_deduplication: request.allow_deduplication,
+            status,
+        };
+        if let Ok(json) = serde_json::to_string(&persisted) {
+            self.storage_manager.put(QUEUE_NAMESPACE, &request.request_id, json);
+        }
+    }
+
+    fn clear_persisted(&self, request_id: &str) {
+        if self.config.persist_queue {
+            self.storage_manager.remove(QUEUE_NAMESPACE, request_id);
+        }
+    }
+
+    /// Aggregate queue stats plus the running `recovered_jobs` count.
+    pub fn get_stats(&self) -> IntegrationStats {
+        IntegrationStats {
+            queue: self.queue.get_stats(),
+            recovered_jobs: *self.recovered_jobs.lock().unwrap_or_else(PoisonError::into_inner),
+        }
+    }
+
+    /// Dequeue the next available request (skipping anything still in retry backoff) and run it
+    /// through `run`. A transient failure re-enqueues the request with exponential backoff up to
+    /// `config.max_retries` attempts rather than failing it immediately; only once retries are
+    /// exhausted does this send a `Failed` `ProgressUpdate` carrying the last error. Before `run`
+    /// is ever called, the request's scenario is validated (see `validate_scenario`); a scenario
+    /// that fails is never executed - it's marked `InvalidJob`, frees its concurrency slot, and
+    /// is reported to subscribers as a terminal update. `run` is the caller-supplied simulation
+    /// executor (e.g. a closure wrapping the actual simulation call), since this module doesn't
+    /// own a simulation runner itself.
+    pub fn process_next_request<F>(&self, run: F) -> Option<BackgroundSimulationId>
+    where
+        F: FnOnce(&SimulationRequest) -> Result<(), String>,
+    {
+        let mut request = self.queue.dequeue()?;
+        request.max_retries = self.config.max_retries;
+        request.retry_backoff_base_ms = self.config.retry_backoff_base_ms;
+        self.persist_request(&request, SimulationRequestStatus::Running);
+
+        let simulation_id = BackgroundSimulationId::from_string(&request.request_id)
+            .unwrap_or_else(|_| BackgroundSimulationId::new());
+
+        if let Err((error, field_path)) = validate_scenario(&request.parameters) {
+            self.queue.mark_completed(&request.request_id);
+            self.queue.set_status(
+                &request.request_id,
+                SimulationRequestStatus::InvalidJob {
+                    error: error.clone(),
+                    field_path: field_path.clone(),
+                },
+            );
+            self.clear_persisted(&request.request_id);
+            let _ = self.progress_comm.send_update(ProgressUpdate::new(
+                simulation_id.clone(),
+                ProgressUpdateType::InvalidJob { field_path },
+                0.0,
+                "InvalidJob",
+            ).with_message(error));
+            return Some(simulation_id);
+        }
+
+        if let Err(error) = run(&request) {
+            let attempt = request.retry_count + 1;
+            let mut rescheduled = request.clone();
+            rescheduled.retry_count = attempt;
+            match self.queue.retry_or_exhaust(request) {
+                Ok(()) => {
+                    self.persist_request(&rescheduled, SimulationRequestStatus::Queued);
+                    let _ = self.send_update(
+                        simulation_id.clone(),
+                        ProgressUpdateType::Progress,
+                        format!("Attempt {attempt} failed, retrying: {error}"),
+                    );
+                }
+                Err(exhausted) => {
+                    self.queue.mark_completed(&exhausted.request_id);
+                    let message = format!("Exhausted {} retries: {error}", exhausted.max_retries);
+                    self.queue.set_status(
+                        &exhausted.request_id,
+                        SimulationRequestStatus::Failed { error: message.clone() },
+                    );
+                    self.clear_persisted(&exhausted.request_id);
+                    let _ = self.send_update(simulation_id.clone(), ProgressUpdateType::Failed, message);
+                }
+            }
+        } else {
+            self.queue.mark_completed(&request.request_id);
+            self.queue
+                .set_status(&request.request_id, SimulationRequestStatus::Completed);
+            self.clear_persisted(&request.request_id);
+        }
+
+        Some(simulation_id)
+    }
+
+    /// Look up the last-known status of a submitted request
+    pub fn get_request_status(&self, request_id: &str) -> Option<SimulationRequestStatus> {
+        self.queue.get_request_status(request_id)
+    }
+
+    fn send_update(
+        &self,
+        simulation_id: BackgroundSimulationId,
+        update_type: ProgressUpdateType,
+        message: String,
+    ) -> Result<(), ProgressError> {
+        self.progress_comm.send_update(
+            ProgressUpdate::new(simulation_id, update_type, 0.0, &message).with_message(message),
+        )
+    }
+
+    /// Per-simulation elapsed time and time-since-last-phase-change, for callers that want to
+    /// surface which running jobs are slow (e.g. a status dashboard).
+    pub fn get_integration_stats(&self) -> Vec<SimulationActivitySnapshot> {
+        self.progress_comm.activity_snapshots()
+    }
+
+    /// Spawn a background watchdog that polls every `progress_update_interval_ms` for simulations
+    /// that have gone quiet or run too long: past `stall_warning_threshold_ms` since their last
+    /// genuine progress update, it broadcasts a `Stalled { since_ms }` update (once per stall, not
+    /// every tick); past `max_runtime_ms` total, it broadcasts `TimedOut`, marks the request
+    /// `SimulationRequestStatus::TimedOut`, clears its persisted record (the same terminal
+    /// bookkeeping `process_next_request`'s `Completed`/`Failed`/`InvalidJob` branches do), and
+    /// frees the concurrency slot via `queue.mark_completed`. Returns the thread's `JoinHandle`
+    /// plus a stop flag the caller can set to `true` to end the loop, mirroring
+    /// `BackgroundSimulation`'s `cancellation_requested` pattern.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_stall_monitor(
+        &self,
+        stall_warning_threshold_ms: u64,
+        max_runtime_ms: u64,
+        progress_update_interval_ms: u64,
+    ) -> (thread::JoinHandle<()>, Arc<Mutex<bool>>) {
+        let queue = self.queue.clone();
+        let progress_comm = self.progress_comm.clone();
+        let storage_manager = self.storage_manager.clone();
+        let persist_queue = self.config.persist_queue;
+        let stop_requested = Arc::new(Mutex::new(false));
+        let stop_requested_thread = Arc::clone(&stop_requested);
+
+        let handle = thread::spawn(move || {
+            let mut already_warned: HashMap<BackgroundSimulationId, ()> = HashMap::new();
+
+            loop {
+                if *stop_requested_thread
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                {
+                    break;
+                }
+
+                for snapshot in progress_comm.activity_snapshots() {
+                    if snapshot.elapsed_ms >= max_runtime_ms {
+                        queue.mark_completed(&snapshot.simulation_id.0);
+                        queue.set_status(&snapshot.simulation_id.0, SimulationRequestStatus::TimedOut);
+                        if persist_queue {
+                            storage_manager.remove(QUEUE_NAMESPACE, &snapshot.simulation_id.0);
+                        }
+                        progress_comm.clear_activity(&snapshot.simulation_id);
+                        already_warned.remove(&snapshot.simulation_id);
+                        let _ = progress_comm.send_update(ProgressUpdate::new(
+                            snapshot.simulation_id.clone(),
+                            ProgressUpdateType::TimedOut,
+                            0.0,
+                            &snapshot.last_phase,
+                        ));
+                    } else if snapshot.since_last_update_ms >= stall_warning_threshold_ms
+                        && already_warned.insert(snapshot.simulation_id.clone(), ()).is_none()
+                    {
+                        let _ = progress_comm.send_update(ProgressUpdate::new(
+                            snapshot.simulation_id.clone(),
+                            ProgressUpdateType::Stalled { since_ms: snapshot.since_last_update_ms },
+                            0.0,
+                            &snapshot.last_phase,
+                        ));
+                        eprintln!(
+                            "simulation {} stalled: no progress for {}ms",
+                            snapshot.simulation_id.0, snapshot.since_last_update_ms
+                        );
+                    } else if snapshot.since_last_update_ms < stall_warning_threshold_ms {
+                        // Fresh progress arrived since the last stall warning - allow a future
+                        // stall on this simulation to be reported again.
+                        already_warned.remove(&snapshot.simulation_id);
+                    }
+                }
+
+                thread::sleep(Duration::from_millis(progress_update_interval_ms));
+            }
+        });
+
+        (handle, stop_requested)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::background_simulation::SimulationPriority;
+    use crate::queue_manager::tests::create_test_creature;
+    use crate::user_interaction::ScenarioParameters;
+
+    fn create_test_parameters() -> ScenarioParameters {
+        ScenarioParameters {
+            players: vec![create_test_creature("Player1", 10.0, 15.0)],
+            encounters: vec![],
+            iterations: 10,
+        }
+    }
+
+    fn make_integration(
+        storage_manager: StorageManager,
+        config: StorageIntegrationConfig,
+    ) -> StorageIntegration {
+        let (progress_comm, _receiver) = ProgressCommunication::new();
+        StorageIntegration::new(storage_manager, SimulationQueue::new(10), progress_comm, config)
+    }
+
+    #[test]
+    fn new_restores_nothing_from_an_empty_storage_manager() {
+        let integration = make_integration(StorageManager::default(), StorageIntegrationConfig::default());
+        assert_eq!(integration.get_stats().recovered_jobs, 0);
+        assert_eq!(integration.get_stats().queue.pending_count, 0);
+    }
+
+    #[test]
+    fn new_recovers_queued_and_running_requests_from_a_carried_over_storage_manager() {
+        let storage_manager = StorageManager::default();
+        let config = StorageIntegrationConfig::default();
+
+        // Simulate a previous StorageIntegration having persisted one queued and one running
+        // request before the process went away.
+        let first = make_integration(storage_manager.clone(), config.clone());
+        let queued = SimulationRequest::new(create_test_parameters(), SimulationPriority::Normal);
+        // Same parameters as `queued`, so deduplication would reject it as a duplicate.
+        let running = SimulationRequest::new(create_test_parameters(), SimulationPriority::High)
+            .without_deduplication();
+        first.submit_request(queued.clone()).unwrap();
+        first.submit_request(running.clone()).unwrap();
+        first.persist_request(&running, SimulationRequestStatus::Running);
+
+        // A fresh StorageIntegration built on the same (carried-over) storage_manager should
+        // recover both without the caller having to call restore_from_storage explicitly.
+        let second = make_integration(storage_manager, config);
+        let stats = second.get_stats();
+        assert_eq!(stats.recovered_jobs, 2);
+        assert_eq!(stats.queue.pending_count, 2);
+    }
+
+    #[test]
+    fn new_does_not_restore_when_persist_queue_is_disabled() {
+        let storage_manager = StorageManager::default();
+        let persisting_config = StorageIntegrationConfig::default();
+
+        let first = make_integration(storage_manager.clone(), persisting_config);
+        first
+            .submit_request(SimulationRequest::new(create_test_parameters(), SimulationPriority::Normal))
+            .unwrap();
+
+        let non_persisting_config = StorageIntegrationConfig {
+            persist_queue: false,
+            ..StorageIntegrationConfig::default()
+        };
+        let second = make_integration(storage_manager, non_persisting_config);
+        assert_eq!(second.get_stats().recovered_jobs, 0);
+        assert_eq!(second.get_stats().queue.pending_count, 0);
+    }
+}
@@ -1,18 +1,64 @@
-// Stub storage_manager module - functionality removed
+// Stub storage_manager module - most functionality removed. `StorageManager` still keeps a
+// minimal in-memory namespaced key-value store: it's the smallest thing a caller needs to
+// round-trip serialized state (e.g. `storage_integration`'s persisted queue) without
+// resurrecting the deleted filesystem-backed storage subsystem `storage_test.rs` once exercised.
+// Note this is in-memory only - it survives for the lifetime of one `StorageManager` instance,
+// not a process restart or WASM page reload, since no browser-storage binding exists in this
+// tree to back it.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[derive(Debug, Clone)]
 pub struct StorageManager {
-    // Stub implementation
+    namespaces: Arc<Mutex<HashMap<String, HashMap<String, String>>>>,
 }
 
 impl Default for StorageManager {
     fn default() -> Self {
-        Self {}
+        Self {
+            namespaces: Arc::new(Mutex::new(HashMap::new())),
+        }
     }
 }
 
 impl StorageManager {
     pub fn clear_cache(&mut self) {
-        // Stub implementation
+        self.namespaces
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Store `value` under `key` within `namespace`, overwriting whatever was there.
+    pub fn put(&self, namespace: &str, key: &str, value: String) {
+        self.namespaces
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+
+    /// Remove `key` from `namespace`, if present.
+    pub fn remove(&self, namespace: &str, key: &str) {
+        if let Some(entries) = self
+            .namespaces
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(namespace)
+        {
+            entries.remove(key);
+        }
+    }
+
+    /// All values currently stored in `namespace`, in no particular order.
+    pub fn values_in_namespace(&self, namespace: &str) -> Vec<String> {
+        self.namespaces
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(namespace)
+            .map(|entries| entries.values().cloned().collect())
+            .unwrap_or_default()
     }
-}
\ No newline at end of file
+}
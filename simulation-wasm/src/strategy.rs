@@ -0,0 +1,162 @@
+// Pluggable per-combatant decision policies, extracted from `execute_turn`'s hard-coded
+// buffs-first heuristic so monster/player stat blocks can opt into a different "brain" via
+// `Creature::ai_mode` (see `crate::enums::AiMode`).
+//
+// Heavier strategies are gated behind Cargo features so a minimal build doesn't pay for
+// logic most scenarios never select: `aggressive_ai` for `Aggressive`, `defensive_ai` for
+// `Defensive`. `GreedyPriority` always ships — it mirrors the scripted default that
+// `execute_turn` runs inline, and is `Defensive`'s fallback. The MCTS engine
+// plugs in separately through `crate::planner::choose_action_mcts` rather than this trait:
+// it needs to clone and mutate combat state mid-search, which this trait's by-reference
+// signature doesn't carry.
+use crate::actions::get_actions_ref;
+use crate::dice;
+use crate::model::*;
+use crate::simulation::{get_action_priority, is_concentration_action};
+
+/// A decision policy for one combatant's turn: given the combat state, choose which
+/// action(s) to take this turn (already D&D action-economy legal, from `get_actions_ref`).
+pub trait CombatStrategy {
+    fn choose_actions<'a>(
+        &self,
+        index: usize,
+        allies: &'a [Combattant],
+        enemies: &'a [Combattant],
+    ) -> Vec<&'a Action>;
+}
+
+/// Whether `action` is blocked by an already-active, non-moveable concentration effect on
+/// `actor` — shared by every strategy so none of them re-cast over their own concentration,
+/// or (for a moveable template like Hunter's Mark/Hex) over a cast that's still worth
+/// keeping. Looks up each template's metadata from the `crate::concentration` registry
+/// rather than hard-coding template names here.
+fn concentration_blocks(actor: &Combattant, action: &Action, allies: &[Combattant], enemies: &[Combattant]) -> bool {
+    if !is_concentration_action(action) {
+        return false;
+    }
+    let Some(current_buff_id) = &actor.final_state.concentrating_on else {
+        return false;
+    };
+
+    let spec = match action {
+        Action::Template(t) => crate::concentration::concentration_registry(&t.template_options.template_name),
+        _ => None,
+    };
+    match spec {
+        Some(spec) if spec.moveable => spec.still_worth_keeping(current_buff_id, allies, enemies),
+        _ => true,
+    }
+}
+
+/// Select up to one Action-slot move and one Bonus-Action-slot move from `candidates`,
+/// ordered by `key` descending (ties keep `candidates`' relative order). Mirrors the
+/// action-economy bookkeeping `execute_turn`'s scripted loop does inline.
+fn pick_one_per_slot<'a>(mut candidates: Vec<&'a Action>, mut key: impl FnMut(&Action) -> f64) -> Vec<&'a Action> {
+    candidates.sort_by(|a, b| key(b).partial_cmp(&key(a)).unwrap_or(std::cmp::Ordering::Equal));
+    let mut used_slots = std::collections::HashSet::new();
+    let mut chosen = Vec::new();
+    for action in candidates {
+        if let Some(slot) = action.base().action_slot {
+            if used_slots.insert(slot) {
+                chosen.push(action);
+            }
+        }
+    }
+    chosen
+}
+
+/// Expected damage `action` deals this turn: average damage × hit chance (vs. a baseline
+/// AC when no explicit target is resolved yet) × number of targets. Untyped/non-attack
+/// actions score 0 so buffs/heals never outrank an attack under `Aggressive`.
+fn expected_turn_damage(action: &Action) -> f64 {
+    let Action::Atk(atk) = action else { return 0.0 };
+    const BASELINE_AC: f64 = 15.0;
+    let to_hit_bonus = dice::average(&atk.to_hit);
+    let needed_roll = BASELINE_AC - to_hit_bonus;
+    let hit_chance = if needed_roll <= 1.0 {
+        0.95
+    } else if needed_roll >= 20.0 {
+        0.05
+    } else {
+        (21.0 - needed_roll) / 20.0
+    };
+    dice::average(&atk.dpr) * hit_chance * atk.targets.max(1) as f64
+}
+
+/// The existing scripted policy: prioritize by `Frequency` (limited > recharge > at will),
+/// then buffs before attacks, filling the Action and Bonus-Action slots greedily. Identical
+/// in spirit to `execute_turn`'s inline sort, extracted so other policies (`Defensive`) can
+/// fall back to it through the `CombatStrategy` interface; no `AiMode` selects it directly.
+pub struct GreedyPriority;
+
+impl CombatStrategy for GreedyPriority {
+    fn choose_actions<'a>(&self, index: usize, allies: &'a [Combattant], enemies: &'a [Combattant]) -> Vec<&'a Action> {
+        let actor = &allies[index];
+        let candidates: Vec<&Action> = get_actions_ref(actor, allies, enemies)
+            .into_iter()
+            .filter(|action| action.base().action_slot.is_some_and(|slot| slot >= 0))
+            .filter(|action| !(action.base().action_slot == Some(1) && actor.final_state.bonus_action_used))
+            .filter(|action| !concentration_blocks(actor, action, allies, enemies))
+            .collect();
+
+        pick_one_per_slot(candidates, |action| {
+            let freq_score = get_action_priority(&action.base().freq) as f64;
+            let buff_bonus = if matches!(action, Action::Buff(_)) { 0.5 } else { 0.0 };
+            freq_score + buff_bonus
+        })
+    }
+}
+
+/// Always takes the action(s) that maximize this turn's expected damage, ignoring buffs
+/// and conditions entirely unless they're the only legal move in a slot.
+#[cfg(feature = "aggressive_ai")]
+pub struct Aggressive;
+
+#[cfg(feature = "aggressive_ai")]
+impl CombatStrategy for Aggressive {
+    fn choose_actions<'a>(&self, index: usize, allies: &'a [Combattant], enemies: &'a [Combattant]) -> Vec<&'a Action> {
+        let actor = &allies[index];
+        let candidates: Vec<&Action> = get_actions_ref(actor, allies, enemies)
+            .into_iter()
+            .filter(|action| action.base().action_slot.is_some_and(|slot| slot >= 0))
+            .filter(|action| !(action.base().action_slot == Some(1) && actor.final_state.bonus_action_used))
+            .filter(|action| !concentration_blocks(actor, action, allies, enemies))
+            .collect();
+
+        pick_one_per_slot(candidates, expected_turn_damage)
+    }
+}
+
+/// Below `HP_THRESHOLD` of max HP, prioritizes self-buffs and healing over anything else;
+/// above it, falls back to `GreedyPriority`.
+#[cfg(feature = "defensive_ai")]
+pub struct Defensive;
+
+#[cfg(feature = "defensive_ai")]
+impl Defensive {
+    const HP_THRESHOLD: f64 = 0.5;
+}
+
+#[cfg(feature = "defensive_ai")]
+impl CombatStrategy for Defensive {
+    fn choose_actions<'a>(&self, index: usize, allies: &'a [Combattant], enemies: &'a [Combattant]) -> Vec<&'a Action> {
+        let actor = &allies[index];
+        let hp_fraction = actor.final_state.current_hp as f64 / actor.creature.hp.max(1) as f64;
+        if hp_fraction >= Self::HP_THRESHOLD {
+            return GreedyPriority.choose_actions(index, allies, enemies);
+        }
+
+        let candidates: Vec<&Action> = get_actions_ref(actor, allies, enemies)
+            .into_iter()
+            .filter(|action| action.base().action_slot.is_some_and(|slot| slot >= 0))
+            .filter(|action| !(action.base().action_slot == Some(1) && actor.final_state.bonus_action_used))
+            .filter(|action| !concentration_blocks(actor, action, allies, enemies))
+            .collect();
+
+        pick_one_per_slot(candidates, |action| match action {
+            Action::Heal(_) => 2.0,
+            Action::Buff(_) => 1.0,
+            _ => 0.0,
+        })
+    }
+}
@@ -0,0 +1,368 @@
+//! Online (single-pass) aggregation for Monte Carlo simulation batches, so a top-level driver
+//! can fold each `SimulationRun` into running statistics and drop it instead of holding
+//! `Vec<SimulationRun>` for the whole batch - see `wasm_api::run_event_driven_simulation_streaming`.
+//! Peak memory is O(1) in the iteration count rather than O(N).
+
+use std::collections::HashMap;
+
+/// Running mean/variance via Welford's algorithm - numerically stable, single-pass, and exact
+/// regardless of how many samples have been folded in (unlike accumulating `sum`/`sum_of_squares`,
+/// which loses precision as the sums grow).
+#[derive(Debug, Clone, Default)]
+pub struct WelfordAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl WelfordAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Sample variance. `0.0` for fewer than 2 samples (no dispersion is defined yet).
+    pub fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count as f64 - 1.0)
+        }
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Standard error of the mean: `std_dev / sqrt(count)`.
+    pub fn std_error(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.std_dev() / (self.count as f64).sqrt()
+        }
+    }
+
+    /// Half-width of the 95% confidence interval on the mean: `1.96 * std_error`. Used by
+    /// `run_adaptive` to decide when an estimate is tight enough to stop sampling.
+    pub fn ci_half_width(&self) -> f64 {
+        1.96 * self.std_error()
+    }
+}
+
+/// Which running statistic an adaptive run's stopping rule watches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrimaryMetric {
+    WinRate,
+    Score,
+}
+
+/// The precision an adaptive run should stop at, expressed either as an absolute CI half-width
+/// (e.g. `Absolute(0.01)` for win-rate +/-1%) or as a fraction of the current mean's magnitude
+/// (e.g. `Relative(0.05)` for +/-5% of whatever the score turns out to be).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrecisionTarget {
+    Absolute(f64),
+    Relative(f64),
+}
+
+impl PrecisionTarget {
+    /// Whether `accumulator`'s current CI half-width is already within this budget.
+    fn satisfied_by(&self, accumulator: &WelfordAccumulator) -> bool {
+        let half_width = accumulator.ci_half_width();
+        match self {
+            PrecisionTarget::Absolute(budget) => half_width <= *budget,
+            PrecisionTarget::Relative(fraction) => half_width <= fraction * accumulator.mean().abs(),
+        }
+    }
+}
+
+/// A run's seed and score, tracked so the run itself can be dropped and re-simulated later from
+/// just the seed - see `StreamingSimulationSummary::representative_seeds`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackedRun {
+    pub seed: u64,
+    pub score: f64,
+}
+
+/// The best-so-far, worst-so-far, and (when available) median seed out of a streamed batch -
+/// enough to replay a handful of representative runs through
+/// `api::runner::run_single_event_driven_simulation` for their full event traces, without having
+/// kept every run's `SimulationResult` in memory. `median` is only populated when the summary was
+/// built with `collect_runs: true`: an exact median needs the full sorted score list, which is
+/// exactly the O(N) data `collected_runs` already exists to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RepresentativeSeeds {
+    pub best: Option<u64>,
+    pub worst: Option<u64>,
+    pub median: Option<u64>,
+}
+
+/// Aggregated outcome of a streamed simulation batch - accumulated one run at a time, never
+/// holding the full `Vec<SimulationRun>` unless `collected_runs` was requested.
+#[derive(Debug, Clone)]
+pub struct StreamingSimulationSummary {
+    pub iterations: usize,
+    pub wins: usize,
+    /// Mean/variance of the per-run win indicator (1.0/0.0) - the win-rate `PrimaryMetric`'s
+    /// accumulator, distinct from the plain `wins` count so its CI half-width is available.
+    pub win_indicator: WelfordAccumulator,
+    pub score: WelfordAccumulator,
+    pub survivors: WelfordAccumulator,
+    /// Total party HP remaining at the end of round `index`, across runs that reached it.
+    pub round_hp: HashMap<usize, WelfordAccumulator>,
+    /// Seed and score of the best-scoring run seen so far.
+    pub best: Option<TrackedRun>,
+    /// Seed and score of the worst-scoring run seen so far.
+    pub worst: Option<TrackedRun>,
+    /// Only populated when the caller passes `collect_runs: true` - the O(N) path kept around
+    /// for the existing Two-Pass system, which needs the full runs to re-select interesting seeds.
+    pub collected_runs: Option<Vec<crate::model::SimulationRun>>,
+}
+
+impl StreamingSimulationSummary {
+    fn new(collect_runs: bool) -> Self {
+        Self {
+            iterations: 0,
+            wins: 0,
+            win_indicator: WelfordAccumulator::new(),
+            score: WelfordAccumulator::new(),
+            survivors: WelfordAccumulator::new(),
+            round_hp: HashMap::new(),
+            best: None,
+            worst: None,
+            collected_runs: if collect_runs { Some(Vec::new()) } else { None },
+        }
+    }
+
+    /// The best/worst/median seeds collected so far - pass these to a replay step (e.g.
+    /// `wasm_api::run_event_driven_simulation_rust` with `seed: Some(...)`,
+    /// `iterations: 1`) to get the full `SimulationRun` + event trace for each.
+    pub fn representative_seeds(&self) -> RepresentativeSeeds {
+        let median = self.collected_runs.as_ref().and_then(|runs| {
+            if runs.is_empty() {
+                return None;
+            }
+            let mut scored: Vec<(u64, f64)> = runs
+                .iter()
+                .map(|r| (r.result.seed, crate::aggregation::calculate_score(&r.result)))
+                .collect();
+            scored.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            scored.get(scored.len() / 2).map(|(seed, _)| *seed)
+        });
+
+        RepresentativeSeeds {
+            best: self.best.map(|t| t.seed),
+            worst: self.worst.map(|t| t.seed),
+            median,
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.iterations == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.iterations as f64
+        }
+    }
+
+    /// The accumulator a `PrimaryMetric` stopping rule watches.
+    pub fn primary_accumulator(&self, metric: PrimaryMetric) -> &WelfordAccumulator {
+        match metric {
+            PrimaryMetric::WinRate => &self.win_indicator,
+            PrimaryMetric::Score => &self.score,
+        }
+    }
+
+    /// Folds one run's result into the running accumulators, then (unless `collect_runs` was
+    /// set) the run can be dropped by the caller - this is the only place state from a run
+    /// needs to survive past its own iteration.
+    fn accumulate(&mut self, run: &crate::model::SimulationRun) {
+        self.iterations += 1;
+
+        let won = crate::simulation::run_is_win(&run.result);
+        if won {
+            self.wins += 1;
+        }
+        self.win_indicator.update(if won { 1.0 } else { 0.0 });
+
+        let score = crate::aggregation::calculate_score(&run.result);
+        self.score.update(score);
+
+        let tracked = TrackedRun { seed: run.result.seed, score };
+        let is_new_best = match self.best {
+            Some(best) => score > best.score,
+            None => true,
+        };
+        if is_new_best {
+            self.best = Some(tracked);
+        }
+        let is_new_worst = match self.worst {
+            Some(worst) => score < worst.score,
+            None => true,
+        };
+        if is_new_worst {
+            self.worst = Some(tracked);
+        }
+
+        if let Some(last_encounter) = run.result.encounters.last() {
+            if let Some(last_round) = last_encounter.rounds.last() {
+                let survivors = last_round.team1.iter().filter(|c| c.final_state.current_hp > 0).count();
+                self.survivors.update(survivors as f64);
+            }
+
+            for (round_index, round) in last_encounter.rounds.iter().enumerate() {
+                let total_hp: f64 = round.team1.iter().map(|c| c.final_state.current_hp as f64).sum();
+                self.round_hp.entry(round_index).or_default().update(total_hp);
+            }
+        }
+    }
+}
+
+/// Folds `run` into `summary`, optionally retaining it in `summary.collected_runs` - the single
+/// entry point `run_event_driven_simulation_streaming`'s per-iteration loop calls so a run never
+/// needs to be held onto past this call unless `collect_runs` was requested.
+pub fn fold_run(summary: &mut StreamingSimulationSummary, run: crate::model::SimulationRun) {
+    summary.accumulate(&run);
+    if let Some(collected) = summary.collected_runs.as_mut() {
+        collected.push(run);
+    }
+}
+
+pub fn new_summary(collect_runs: bool) -> StreamingSimulationSummary {
+    StreamingSimulationSummary::new(collect_runs)
+}
+
+/// Result of an adaptive (CI-early-stopping) run: the final summary plus how tight the
+/// `PrimaryMetric` estimate ended up, so callers can see exactly how much precision their
+/// iteration budget bought.
+#[derive(Debug, Clone)]
+pub struct AdaptiveSimulationOutcome {
+    pub summary: StreamingSimulationSummary,
+    pub achieved_precision: f64,
+    pub iterations_run: usize,
+}
+
+/// Error-budget-driven iteration loop: calls `produce_run` to get one more `SimulationRun`,
+/// folds it into the summary, and keeps going until `metric`'s 95% CI half-width satisfies
+/// `precision` - but never stops before `min_iters` (too few samples make the CI unreliable) or
+/// runs past `max_iters` (the hard ceiling that avoids the `run_event_driven_simulation_rust`
+/// O(N) OOM case even if precision is never reached). Generic over the run producer so both the
+/// `Vec<Creature>`/`TimelineStep` driver in `wasm_api` and any other caller that produces
+/// `SimulationRun`s can reuse the same stopping rule.
+pub fn run_adaptive(
+    min_iters: usize,
+    max_iters: usize,
+    metric: PrimaryMetric,
+    precision: PrecisionTarget,
+    collect_runs: bool,
+    mut produce_run: impl FnMut(usize) -> crate::model::SimulationRun,
+) -> AdaptiveSimulationOutcome {
+    let mut summary = StreamingSimulationSummary::new(collect_runs);
+
+    for i in 0..max_iters.max(min_iters) {
+        let run = produce_run(i);
+        fold_run(&mut summary, run);
+
+        if summary.iterations >= min_iters && precision.satisfied_by(summary.primary_accumulator(metric)) {
+            break;
+        }
+    }
+
+    let achieved_precision = summary.primary_accumulator(metric).ci_half_width();
+    let iterations_run = summary.iterations;
+
+    AdaptiveSimulationOutcome { summary, achieved_precision, iterations_run }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_welford_matches_naive_mean_and_variance() {
+        let samples = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut acc = WelfordAccumulator::new();
+        for &s in &samples {
+            acc.update(s);
+        }
+
+        let naive_mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+        let naive_variance: f64 = samples.iter().map(|x| (x - naive_mean).powi(2)).sum::<f64>() / (samples.len() as f64 - 1.0);
+
+        assert!((acc.mean() - naive_mean).abs() < 1e-9);
+        assert!((acc.variance() - naive_variance).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_welford_single_sample_has_zero_variance() {
+        let mut acc = WelfordAccumulator::new();
+        acc.update(42.0);
+        assert_eq!(acc.mean(), 42.0);
+        assert_eq!(acc.variance(), 0.0);
+        assert_eq!(acc.std_error(), 0.0);
+    }
+
+    #[test]
+    fn test_precision_target_absolute_uses_ci_half_width() {
+        let mut acc = WelfordAccumulator::new();
+        for x in [1.0, 1.0, 1.0, 1.0, 1.0] {
+            acc.update(x);
+        }
+        // Zero variance -> zero half-width, so any absolute budget is already satisfied.
+        assert!(PrecisionTarget::Absolute(0.0).satisfied_by(&acc));
+    }
+
+    #[test]
+    fn test_precision_target_relative_scales_with_mean() {
+        let mut acc = WelfordAccumulator::new();
+        for x in [10.0, 10.0, 10.0] {
+            acc.update(x);
+        }
+        assert!(PrecisionTarget::Relative(0.5).satisfied_by(&acc));
+    }
+
+    #[test]
+    fn test_summary_without_collect_runs_drops_runs() {
+        let summary = new_summary(false);
+        assert!(summary.collected_runs.is_none());
+    }
+
+    #[test]
+    fn test_summary_with_collect_runs_retains_runs() {
+        let summary = new_summary(true);
+        assert!(summary.collected_runs.is_some());
+    }
+
+    #[test]
+    fn test_representative_seeds_exposes_best_and_worst_without_collected_runs() {
+        let mut summary = new_summary(false);
+        summary.best = Some(TrackedRun { seed: 11, score: 42.0 });
+        summary.worst = Some(TrackedRun { seed: 22, score: -7.0 });
+
+        let seeds = summary.representative_seeds();
+        assert_eq!(seeds.best, Some(11));
+        assert_eq!(seeds.worst, Some(22));
+        assert_eq!(seeds.median, None);
+    }
+
+    #[test]
+    fn test_representative_seeds_median_none_when_no_runs_collected() {
+        let summary = new_summary(true);
+        assert_eq!(summary.representative_seeds().median, None);
+    }
+}
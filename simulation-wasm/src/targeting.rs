@@ -2,7 +2,102 @@ use crate::dice;
 use crate::enums::*;
 use crate::model::*;
 use crate::combat_stats::CombatantStats;
+use std::cell::RefCell;
 use std::cmp::Ordering;
+use std::collections::HashSet;
+
+// Per-round "who's already been focus-fired" bookkeeping. Reset once per round (see
+// `reset_focus_fire_claims`) so each team gets a fresh claim set; the two thread-locals
+// are kept separate because team1's enemy indices (into team2) and team2's enemy indices
+// (into team1) refer to different slices.
+thread_local! {
+    static FOCUS_FIRE_CLAIMED_TEAM1: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+    static FOCUS_FIRE_CLAIMED_TEAM2: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+/// Clear the focus-fire claim sets. Call once at the start of each round so fire can
+/// spread across a fresh set of enemies every round.
+pub fn reset_focus_fire_claims() {
+    FOCUS_FIRE_CLAIMED_TEAM1.with(|c| c.borrow_mut().clear());
+    FOCUS_FIRE_CLAIMED_TEAM2.with(|c| c.borrow_mut().clear());
+}
+
+/// Expected damage `attacker`'s attack would deal to `target` this swing, after hit
+/// chance and the target's damage-type resistance/vulnerability/immunity.
+fn expected_damage_against(atk: &AtkAction, target: &Combattant) -> f64 {
+    let to_hit_bonus = dice::average(&atk.to_hit);
+    let needed_roll = target.creature.ac as f64 - to_hit_bonus;
+    let hit_chance = if needed_roll <= 1.0 {
+        0.95
+    } else if needed_roll >= 20.0 {
+        0.05
+    } else {
+        (21.0 - needed_roll) / 20.0
+    };
+
+    let base_damage = dice::average(&atk.dpr);
+    let (multiplier, _) = target.creature.damage_type_modifier(atk.damage_type);
+
+    base_damage * hit_chance * multiplier
+}
+
+/// "Focus fire" target selection: among living, unclaimed enemies, pick the one this
+/// attack would deal the most actual damage to. Ties break on remaining HP (lower wins, so
+/// a near-dead target gets finished off before a full-health one) then on higher
+/// initiative. Claims are tracked per-team per-round so fire spreads instead of piling onto
+/// one target, unless every other living enemy is already claimed.
+fn select_focus_fire_target(
+    atk: &AtkAction,
+    enemies: &[Combattant],
+    excluded: &[(bool, usize)],
+    claimed: &RefCell<HashSet<usize>>,
+) -> Option<usize> {
+    let living: Vec<usize> = enemies
+        .iter()
+        .enumerate()
+        .filter(|(i, e)| e.final_state.current_hp > 0 && !excluded.contains(&(true, *i)))
+        .map(|(i, _)| i)
+        .collect();
+
+    if living.is_empty() {
+        return None;
+    }
+
+    let all_claimed = {
+        let c = claimed.borrow();
+        living.iter().all(|i| c.contains(i))
+    };
+    let candidates: Vec<usize> = if all_claimed {
+        living
+    } else {
+        let c = claimed.borrow();
+        living.into_iter().filter(|i| !c.contains(i)).collect()
+    };
+
+    let best = candidates.into_iter().max_by(|&a, &b| {
+        let dmg_a = expected_damage_against(atk, &enemies[a]);
+        let dmg_b = expected_damage_against(atk, &enemies[b]);
+        dmg_a
+            .partial_cmp(&dmg_b)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| {
+                // Lower remaining HP wins the tie (finish off the near-dead target first).
+                enemies[b].final_state.current_hp.cmp(&enemies[a].final_state.current_hp)
+            })
+            .then_with(|| {
+                enemies[a]
+                    .initiative
+                    .partial_cmp(&enemies[b].initiative)
+                    .unwrap_or(Ordering::Equal)
+            })
+    });
+
+    if let Some(idx) = best {
+        claimed.borrow_mut().insert(idx);
+    }
+
+    best
+}
 
 pub fn get_targets(
     c: &Combattant,
@@ -33,7 +128,20 @@ pub fn get_targets(
                 );
                 // For attacks, we allow targeting the same enemy multiple times (e.g. Multiattack, Scorching Ray)
                 // So we pass an empty excluded list.
-                if let Some(idx) = select_enemy_target(c, a.target.clone(), enemies, &[], None) {
+                let selected = if a.target == EnemyTarget::FocusFire {
+                    if c.team == 0 {
+                        FOCUS_FIRE_CLAIMED_TEAM1.with(|claimed| {
+                            select_focus_fire_target(a, enemies, &[], claimed)
+                        })
+                    } else {
+                        FOCUS_FIRE_CLAIMED_TEAM2.with(|claimed| {
+                            select_focus_fire_target(a, enemies, &[], claimed)
+                        })
+                    }
+                } else {
+                    select_enemy_target(c, a.target.clone(), enemies, &[], None)
+                };
+                if let Some(idx) = selected {
                     #[cfg(debug_assertions)]
                     eprintln!(
                         "            Target selected for {}: Enemy {}",
@@ -256,6 +364,7 @@ pub fn select_enemy_target(
             EnemyTarget::EnemyWithLeastHP => e1.final_state.current_hp,
             EnemyTarget::EnemyWithMostHP => -e1.final_state.current_hp,
             EnemyTarget::EnemyWithHighestDPR => -estimate_dpr(e1),
+            EnemyTarget::FocusFire => -estimate_dpr(e1),
             EnemyTarget::EnemyWithLowestAC => est_ac1,
             EnemyTarget::EnemyWithHighestAC => -est_ac1,
         };
@@ -264,6 +373,7 @@ pub fn select_enemy_target(
             EnemyTarget::EnemyWithLeastHP => e2.final_state.current_hp,
             EnemyTarget::EnemyWithMostHP => -e2.final_state.current_hp,
             EnemyTarget::EnemyWithHighestDPR => -estimate_dpr(e2),
+            EnemyTarget::FocusFire => -estimate_dpr(e2),
             EnemyTarget::EnemyWithLowestAC => est_ac2,
             EnemyTarget::EnemyWithHighestAC => -est_ac2,
         };
@@ -318,6 +428,7 @@ pub fn select_enemy_target(
                 EnemyTarget::EnemyWithHighestDPR => -estimate_dpr(e),
                 EnemyTarget::EnemyWithLowestAC => e.creature.ac,
                 EnemyTarget::EnemyWithHighestAC => -e.creature.ac,
+                EnemyTarget::FocusFire => -estimate_dpr(e),
             };
             println!("  - Candidate {}: Score {:.1}", e.creature.name, val);
         }
@@ -461,6 +572,7 @@ pub fn select_enemy_target_cached(
                 EnemyTarget::EnemyWithHighestDPR => -stats.total_dpr,
                 EnemyTarget::EnemyWithLowestAC => e.creature.ac,
                 EnemyTarget::EnemyWithHighestAC => -e.creature.ac,
+                EnemyTarget::FocusFire => -stats.total_dpr,
             };
             println!("  - Candidate {}: Score {:.1} (DPR: {:.1})", e.creature.name, val, stats.total_dpr);
         }
@@ -645,11 +757,13 @@ fn calculate_target_score_cached(
         EnemyTarget::EnemyWithHighestDPR => -target_stats.total_dpr,
         EnemyTarget::EnemyWithLowestAC => attacker_estimated_ac,
         EnemyTarget::EnemyWithHighestAC => -attacker_estimated_ac,
+        EnemyTarget::FocusFire => -target_stats.total_dpr,
     }
 }
 
-/// Legacy DPR estimation function - kept for compatibility
-fn estimate_dpr(c: &Combattant) -> f64 {
+/// Legacy DPR estimation function - kept for compatibility. Also doubles as the
+/// "effective power" metric (`simulation::run_round` uses it to break initiative ties).
+pub(crate) fn estimate_dpr(c: &Combattant) -> f64 {
     const BASELINE_AC: f64 = 15.0;
 
     // Separate actions by action type for proper action economy
@@ -22,6 +22,9 @@
 
 use crate::model::{Creature, TimelineStep};
 
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+
 /// Two-Pass Deterministic Re-simulation implementation
 ///
 /// Original Two-Pass system with 10% granularity and simple seed selection.
@@ -63,17 +66,27 @@ pub fn run_simulation_with_rolling_stats(
             .map(|s| s.seed)
             .collect();
 
-    // Phase 3: Deep dive pass - re-run interesting seeds with full events
-    let mut sample_runs = Vec::new();
-    for seed in &interesting_seeds {
-        crate::rng::seed_rng(*seed);
+    // Phase 3: Deep dive pass - re-run interesting seeds with full events. `seed` is passed
+    // explicitly into `run_single_event_driven_simulation` rather than via the thread-local RNG,
+    // so no iteration observes another's RNG state regardless of which rayon worker picks it up -
+    // re-simulation stays reproducible under `par_iter` just like the survey pass is.
+    let resimulate_seed = |seed: u64| -> crate::model::SimulationRun {
         let (result, events) =
-            crate::run_single_event_driven_simulation(&players, &timeline, false);
-        sample_runs.push(crate::model::SimulationRun { result, events });
-    }
+            crate::run_single_event_driven_simulation(&players, &timeline, seed, false);
+        crate::model::SimulationRun { result, events }
+    };
 
-    // Clear the seeded RNG after simulation completes
-    crate::rng::clear_rng();
+    #[cfg(not(target_arch = "wasm32"))]
+    let sample_runs: Vec<crate::model::SimulationRun> = interesting_seeds
+        .par_iter()
+        .map(|&seed| resimulate_seed(seed))
+        .collect();
+
+    #[cfg(target_arch = "wasm32")]
+    let sample_runs: Vec<crate::model::SimulationRun> = interesting_seeds
+        .iter()
+        .map(|&seed| resimulate_seed(seed))
+        .collect();
 
     // Calculate statistics from lightweight runs
     let mut sorted_scores: Vec<f64> =
@@ -180,21 +193,27 @@ pub fn run_simulation_with_three_tier(
     // Phase 3: Deep dive pass - re-run selected seeds with tier-appropriate event collection
     let mut sample_runs = Vec::new();
     for selected_seed in &selected_seeds {
-        crate::rng::seed_rng(selected_seed.seed);
-
         match selected_seed.tier {
             crate::model::InterestingSeedTier::TierA => {
                 // Full events for decile logs
-                let (result, events) =
-                    crate::run_single_event_driven_simulation(&players, &timeline, false);
+                let (result, events) = crate::run_single_event_driven_simulation(
+                    &players,
+                    &timeline,
+                    selected_seed.seed,
+                    false,
+                );
                 sample_runs.push(crate::model::SimulationRun { result, events });
             }
             crate::model::InterestingSeedTier::TierB => {
                 // Lean events for 1% medians
                 // TODO: For now, we run full events but store fewer runs
                 // In a future update, we'd use execute_encounter_lean() for true lean collection
-                let (result, events) =
-                    crate::run_single_event_driven_simulation(&players, &timeline, false);
+                let (result, events) = crate::run_single_event_driven_simulation(
+                    &players,
+                    &timeline,
+                    selected_seed.seed,
+                    false,
+                );
                 sample_runs.push(crate::model::SimulationRun { result, events });
             }
             crate::model::InterestingSeedTier::TierC => {
@@ -205,9 +224,6 @@ pub fn run_simulation_with_three_tier(
         }
     }
 
-    // Clear the seeded RNG after simulation completes
-    crate::rng::clear_rng();
-
     // Calculate statistics from lightweight runs
     let mut sorted_scores: Vec<f64> =
         lightweight_runs.iter().map(|r| r.final_score).collect();
@@ -62,9 +62,8 @@ impl ChunkedSimulationRunner {
         
         for i in self.current_iteration..end {
             let seed = self.base_seed.wrapping_add(i as u64);
-            crate::rng::seed_rng(seed);
 
-            let (result, _) = crate::simulation::run_single_event_driven_simulation(&self.players, &self.timeline, false);
+            let (result, _) = crate::api::runner::run_single_event_driven_simulation(&self.players, &self.timeline, seed, false);
 
             let score = crate::aggregation::calculate_score(&result);
             let mut encounter_scores = Vec::new();
@@ -108,8 +107,7 @@ impl ChunkedSimulationRunner {
         let median_seed = self.lightweight_runs[global_scores[global_scores.len() / 2].0].seed;
 
         for &seed in &interesting_seeds {
-            crate::rng::seed_rng(seed);
-            let (_, events) = crate::simulation::run_single_event_driven_simulation(&self.players, &self.timeline, true);
+            let (_, events) = crate::api::runner::run_single_event_driven_simulation(&self.players, &self.timeline, seed, true);
 
             if seed == median_seed {
                 median_run_events = events.clone();
@@ -275,9 +273,8 @@ pub fn run_simulation_with_callback(
 
     for i in 0..iterations {
         let seed = i as u64; // Simple deterministic seed for now
-        crate::rng::seed_rng(seed);
 
-        let (result, _) = crate::simulation::run_single_event_driven_simulation(&players, &timeline, false);
+        let (result, _) = crate::api::runner::run_single_event_driven_simulation(&players, &timeline, seed, false);
 
         // Store for full analysis later (summarized to save memory)
         let score = crate::aggregation::calculate_score(&result);
@@ -331,8 +328,7 @@ pub fn run_simulation_with_callback(
     let median_seed = lightweight_runs[global_scores[global_scores.len() / 2].0].seed;
 
     for (idx, &seed) in interesting_seeds.iter().enumerate() {
-        crate::rng::seed_rng(seed);
-        let (_, events) = crate::simulation::run_single_event_driven_simulation(&players, &timeline, true);
+        let (_, events) = crate::api::runner::run_single_event_driven_simulation(&players, &timeline, seed, true);
 
         if seed == median_seed {
             median_run_events = events.clone();
@@ -423,7 +419,7 @@ pub fn run_event_driven_simulation(players: JsValue, timeline: JsValue, iteratio
     let mut results = Vec::new();
 
     for i in 0..iterations {
-        let (result, events) = crate::simulation::run_single_event_driven_simulation(&players, &timeline, i == 0);
+        let (result, events) = crate::api::runner::run_single_event_driven_simulation(&players, &timeline, i as u64, i == 0);
         results.push(result);
 
         if i == 0 {
@@ -610,11 +606,9 @@ pub fn run_event_driven_simulation_rust(
     for i in 0..iterations {
         // If a seed is provided, use it with the iteration index for determinism
         // This ensures each iteration is deterministic but different from others
-        if let Some(s) = seed {
-            crate::rng::seed_rng(s.wrapping_add(i as u64));
-        }
+        let iteration_seed = seed.map(|s| s.wrapping_add(i as u64)).unwrap_or(i as u64);
 
-        let (result, events) = crate::simulation::run_single_event_driven_simulation(&players, &timeline, true);
+        let (result, events) = crate::api::runner::run_single_event_driven_simulation(&players, &timeline, iteration_seed, true);
         let run = crate::model::SimulationRun {
             result,
             events,
@@ -622,11 +616,6 @@ pub fn run_event_driven_simulation_rust(
         all_runs.push(run);
     }
 
-    // Clear the seeded RNG after simulation completes
-    if seed.is_some() {
-        crate::rng::clear_rng();
-    }
-
     // Sort results by score (worst to best) with safe comparison
     all_runs.sort_by(|a, b| {
         let score_a = crate::aggregation::calculate_score(&a.result);
@@ -637,6 +626,75 @@ pub fn run_event_driven_simulation_rust(
     all_runs
 }
 
+/// Streaming counterpart to `run_event_driven_simulation_rust`: folds each run into
+/// `streaming_stats::StreamingSimulationSummary` via Welford's algorithm and drops it, so peak
+/// memory is O(1) in `iterations` instead of O(N). Pass `collect_runs: true` to additionally
+/// retain every run (the existing O(N) behavior), which the Two-Pass system still needs to
+/// re-select interesting seeds.
+pub fn run_event_driven_simulation_streaming(
+    players: Vec<Creature>,
+    timeline: Vec<TimelineStep>,
+    iterations: usize,
+    seed: Option<u64>,
+    collect_runs: bool,
+) -> crate::streaming_stats::StreamingSimulationSummary {
+    let mut summary = crate::streaming_stats::new_summary(collect_runs);
+
+    for i in 0..iterations {
+        let iteration_seed = seed.map(|s| s.wrapping_add(i as u64)).unwrap_or(i as u64);
+
+        let (result, events) = crate::api::runner::run_single_event_driven_simulation(&players, &timeline, iteration_seed, true);
+        let run = crate::model::SimulationRun { result, events };
+        crate::streaming_stats::fold_run(&mut summary, run);
+    }
+
+    summary
+}
+
+/// Adaptive counterpart to `run_event_driven_simulation_streaming`: stops sampling once
+/// `metric`'s 95% confidence interval is within `precision` instead of running a fixed
+/// iteration count - see `streaming_stats::run_adaptive`. Still bounded by `min_iters`/`max_iters`
+/// so the result is reproducible per-seed and never runs away on a metric that won't converge.
+pub fn run_event_driven_simulation_adaptive(
+    players: Vec<Creature>,
+    timeline: Vec<TimelineStep>,
+    seed: Option<u64>,
+    min_iters: usize,
+    max_iters: usize,
+    metric: crate::streaming_stats::PrimaryMetric,
+    precision: crate::streaming_stats::PrecisionTarget,
+    collect_runs: bool,
+) -> crate::streaming_stats::AdaptiveSimulationOutcome {
+    let outcome = crate::streaming_stats::run_adaptive(min_iters, max_iters, metric, precision, collect_runs, |i| {
+        let iteration_seed = seed.map(|s| s.wrapping_add(i as u64)).unwrap_or(i as u64);
+        let (result, events) = crate::api::runner::run_single_event_driven_simulation(&players, &timeline, iteration_seed, true);
+        crate::model::SimulationRun { result, events }
+    });
+
+    outcome
+}
+
+/// Re-simulates the seeds identified by `streaming_stats::StreamingSimulationSummary::representative_seeds`
+/// through the full event-driven simulation, so callers who streamed their way through a large
+/// batch (and therefore never held the full `SimulationResult`s in memory) can still get the
+/// complete round-by-round trace for the best-scoring, worst-scoring, and median-scoring runs
+/// they just saw. Re-seeds deterministically per seed, the same way `run_event_driven_simulation_rust`
+/// already does, so each replay is bit-for-bit identical to the original run that produced it.
+pub fn replay_representative_runs(
+    players: Vec<Creature>,
+    timeline: Vec<TimelineStep>,
+    seeds: crate::streaming_stats::RepresentativeSeeds,
+) -> Vec<crate::model::SimulationRun> {
+    [seeds.best, seeds.worst, seeds.median]
+        .into_iter()
+        .flatten()
+        .map(|seed| {
+            let (result, events) =
+                crate::api::runner::run_single_event_driven_simulation(&players, &timeline, seed, true);
+            crate::model::SimulationRun { result, events }
+        })
+        .collect()
+}
 
 // Global storage manager for WASM interface
 static STORAGE_MANAGER: OnceLock<Mutex<StorageManager>> = OnceLock::new();
@@ -648,6 +706,20 @@ fn get_storage_manager() -> &'static Mutex<StorageManager> {
 
 #[wasm_bindgen]
 pub fn run_decile_analysis_wasm(results: JsValue, scenario_name: &str, _party_size: usize) -> Result<JsValue, JsValue> {
+    run_decile_analysis_wasm_impl(results, scenario_name, crate::aggregation::ScoreConfig::default())
+}
+
+/// Same as `run_decile_analysis_wasm`, but lets the caller supply a non-default `ScoreConfig` -
+/// e.g. a "survival-only" profile (`hpWeight: 0.0`) that ignores leftover HP entirely.
+#[wasm_bindgen]
+pub fn run_decile_analysis_wasm_with_config(results: JsValue, scenario_name: &str, _party_size: usize, score_config: JsValue) -> Result<JsValue, JsValue> {
+    let config: crate::aggregation::ScoreConfig = serde_wasm_bindgen::from_value(score_config)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse score config: {}", e)))?;
+
+    run_decile_analysis_wasm_impl(results, scenario_name, config)
+}
+
+fn run_decile_analysis_wasm_impl(results: JsValue, scenario_name: &str, config: crate::aggregation::ScoreConfig) -> Result<JsValue, JsValue> {
     // Add debug logging
     console::log_1(&"=== Decile Analysis WASM Debug ===".into());
 
@@ -658,8 +730,8 @@ pub fn run_decile_analysis_wasm(results: JsValue, scenario_name: &str, _party_si
 
     // Sort results by score from worst to best performance with safe comparison
     results.sort_by(|a, b| {
-        let score_a = crate::aggregation::calculate_score(a);
-        let score_b = crate::aggregation::calculate_score(b);
+        let score_a = crate::aggregation::calculate_score_with_config(a, &config);
+        let score_b = crate::aggregation::calculate_score_with_config(b, &config);
         score_a.partial_cmp(&score_b).unwrap_or(std::cmp::Ordering::Equal)
     });
 
@@ -679,7 +751,7 @@ pub fn run_decile_analysis_wasm(results: JsValue, scenario_name: &str, _party_si
     console::log_1(&format!("Calculated party size: {}", actual_party_size).into());
 
     // 1. Run Overall Analysis (Adventure-wide)
-    let overall = crate::decile_analysis::run_decile_analysis(&results, scenario_name, actual_party_size);
+    let overall = crate::decile_analysis::run_decile_analysis_with_config(&results, scenario_name, actual_party_size, &config);
 
     // 2. Run Per-Encounter Analysis
     // Determine number of encounters from the first result
@@ -688,7 +760,7 @@ pub fn run_decile_analysis_wasm(results: JsValue, scenario_name: &str, _party_si
 
     for i in 0..num_encounters {
         let encounter_name = format!("Encounter {}", i + 1);
-        let analysis = crate::decile_analysis::run_encounter_analysis(&results, i, &encounter_name, actual_party_size);
+        let analysis = crate::decile_analysis::run_encounter_analysis_with_config(&results, i, &encounter_name, actual_party_size, &config);
         encounters.push(analysis);
     }
 
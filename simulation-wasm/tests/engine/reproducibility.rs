@@ -1,6 +1,5 @@
 use simulation_wasm::run_single_event_driven_simulation;
 use simulation_wasm::run_survey_pass;
-use simulation_wasm::rng;
 use crate::common::load_scenario;
 
 #[test]
@@ -26,12 +25,7 @@ fn test_two_pass_reproducibility() {
         assert_eq!(lightweight_run.seed, expected_seed, "Seed mismatch at index {}", index);
 
         // 3. Re-run with Full Simulation (Phase 3 equivalent for single run)
-        rng::seed_rng(expected_seed);
-        
-        let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, false);
-        
-        // Clear RNG after to be clean
-        rng::clear_rng();
+        let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, expected_seed, false);
 
         // 4. Compare Metrics
         let full_score = full_result.score.expect("Full simulation should return a score");
@@ -68,9 +62,7 @@ fn test_reproducibility_complex_mechanics() {
     for (index, lightweight_run) in survey_runs.iter().enumerate() {
         let expected_seed = base_seed + index as u64;
         
-        rng::seed_rng(expected_seed);
-        let (full_result, _) = run_single_event_driven_simulation(&players, &timeline, false);
-        rng::clear_rng();
+        let (full_result, _) = run_single_event_driven_simulation(&players, &timeline, expected_seed, false);
 
         let full_score = full_result.score.unwrap();
         
@@ -228,10 +228,8 @@ fn test_re_simulation_matches_lightweight_scores() {
     let lightweight = run_single_lightweight_simulation(&players, &timeline, seed);
 
     // Run full version with same seed
-    simulation_wasm::rng::seed_rng(seed);
-    let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, false);
+    let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, seed, false);
     let full_score = simulation_wasm::aggregation::calculate_score(&full_result);
-    simulation_wasm::rng::clear_rng();
 
     // Scores should match exactly
     assert_eq!(
@@ -330,10 +328,8 @@ fn test_two_pass_consistency() {
             "Seed from select_interesting_seeds_with_tiers should exist in lightweight_runs",
         );
 
-        simulation_wasm::rng::seed_rng(seed);
-        let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, false);
+        let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, seed, false);
         let full_score = simulation_wasm::aggregation::calculate_score(&full_result);
-        simulation_wasm::rng::clear_rng();
 
         assert_eq!(
             lightweight.final_score, full_score,
@@ -380,12 +376,14 @@ fn test_decile_approximation_accuracy() {
     // 2. Run full simulation (One-Pass) manually for comparison
     let mut full_scores = Vec::new();
     for i in 0..iterations {
-        simulation_wasm::rng::seed_rng(seed + i as u64);
-        let (res, _) =
-            simulation_wasm::run_single_event_driven_simulation(&players, &timeline, false);
+        let (res, _) = simulation_wasm::run_single_event_driven_simulation(
+            &players,
+            &timeline,
+            seed + i as u64,
+            false,
+        );
         full_scores.push(simulation_wasm::aggregation::calculate_score(&res));
     }
-    simulation_wasm::rng::clear_rng();
     full_scores.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
     let true_median = full_scores[iterations / 2];
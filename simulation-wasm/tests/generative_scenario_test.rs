@@ -0,0 +1,277 @@
+// Property-based scenario generator with automatic shrinking of failing cases.
+//
+// Unlike `property_tests.rs` (which drives `proptest`'s own `Strategy`/shrinking machinery),
+// this harness builds scenarios directly from a flat byte buffer so a failing buffer can be
+// shrunk by simple truncation/byte-removal without needing a `Strategy` implementation for
+// `Creature`/`TimelineStep`. A minimized failing buffer is replayed into a scenario and written
+// to `tests/scenarios` as a permanent JSON regression fixture (mirroring the hand-written
+// scenarios `reproducibility_test.rs` loads from the same directory).
+
+use simulation_wasm::model::{
+    Action, ActionCondition, AtkAction, Creature, DiceFormula, Encounter, Frequency, TargetRole,
+    TimelineStep,
+};
+use simulation_wasm::enums::EnemyTarget;
+use simulation_wasm::{run_single_event_driven_simulation, run_single_lightweight_simulation};
+use std::fs;
+use std::path::PathBuf;
+
+/// A cursor over a fixed byte buffer - the single source of randomness for scenario
+/// generation. Reading past the end yields `0` rather than panicking, so a shrunk (shorter)
+/// buffer still generates a well-formed (if smaller/plainer) scenario instead of erroring out.
+struct ByteStream<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteStream<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        let b = self.bytes.get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        b
+    }
+
+    /// An integer in `[lo, hi]`, consuming one byte.
+    fn next_range(&mut self, lo: u32, hi: u32) -> u32 {
+        let span = hi - lo + 1;
+        lo + (self.next_byte() as u32 % span)
+    }
+}
+
+/// Deterministic splitmix64 - used only to generate the raw byte buffers fed to
+/// `ByteStream`, not the combat engine's own RNG (`simulation_wasm::rng`), so scenario
+/// generation and combat resolution stay on independent streams.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len).map(|_| splitmix64(&mut state) as u8).collect()
+}
+
+fn gen_creature(bytes: &mut ByteStream, id: &str, mode: &str) -> Creature {
+    let hp = bytes.next_range(5, 40);
+    let ac = bytes.next_range(8, 18);
+    let dpr_die = bytes.next_range(4, 10);
+    let dpr_bonus = bytes.next_range(0, 5);
+
+    Creature {
+        id: id.to_string(),
+        arrival: None,
+        mode: mode.to_string(),
+        name: id.to_string(),
+        count: 1.0,
+        hp,
+        ac,
+        speed_fly: None,
+        save_bonus: 0.0,
+        str_save_bonus: None,
+        dex_save_bonus: None,
+        con_save_bonus: None,
+        int_save_bonus: None,
+        wis_save_bonus: None,
+        cha_save_bonus: None,
+        con_save_advantage: None,
+        save_advantage: None,
+        initiative_bonus: DiceFormula::Value(0.0),
+        initiative_advantage: false,
+        actions: vec![Action::Atk(AtkAction {
+            id: format!("{}-attack", id),
+            name: "Attack".to_string(),
+            action_slot: None,
+            cost: vec![],
+            requirements: vec![],
+            tags: vec![],
+            freq: Frequency::Static("at will".to_string()),
+            condition: ActionCondition::Default,
+            targets: 1,
+            dpr: DiceFormula::Expr(format!("1d{}+{}", dpr_die, dpr_bonus)),
+            target: EnemyTarget::EnemyWithMostHP,
+            to_hit: DiceFormula::Value(5.0),
+            use_saves: None,
+            half_on_save: None,
+            rider_effect: None,
+        })],
+        triggers: vec![],
+        spell_slots: None,
+        class_resources: None,
+        hit_dice: None,
+        con_modifier: None,
+        ai_mode: Default::default(),
+        mcts_iterations: None,
+    }
+}
+
+/// Builds a small-but-valid scenario (1-2 players vs. 1-2 monsters, a single combat
+/// encounter) entirely from `bytes`. Bounded deliberately: a wider space would make shrinking
+/// slower without exercising more of the engine per the complexity it adds.
+fn gen_scenario(bytes: &mut ByteStream) -> (Vec<Creature>, Vec<TimelineStep>) {
+    let num_players = bytes.next_range(1, 2);
+    let num_monsters = bytes.next_range(1, 2);
+
+    let players: Vec<Creature> = (0..num_players)
+        .map(|i| gen_creature(bytes, &format!("player-{}", i), "player"))
+        .collect();
+    let monsters: Vec<Creature> = (0..num_monsters)
+        .map(|i| gen_creature(bytes, &format!("monster-{}", i), "monster"))
+        .collect();
+
+    let encounter = Encounter {
+        monsters,
+        players_surprised: None,
+        monsters_surprised: None,
+        players_precast: None,
+        monsters_precast: None,
+        target_role: TargetRole::Standard,
+    };
+
+    (players, vec![TimelineStep::Combat(encounter)])
+}
+
+/// Checks the invariants the request calls out: total HP lost never exceeds the party's
+/// starting HP, survivors are consistent between the lightweight and full passes (as in
+/// `reproducibility_test.rs::test_two_pass_reproducibility`), and the score is finite.
+/// Returns `Err` describing the first violated invariant.
+fn check_invariants(players: &[Creature], timeline: &[TimelineStep], seed: u64) -> Result<(), String> {
+    let starting_hp: f64 = players.iter().map(|p| p.hp as f64).sum();
+
+    let lightweight_run = run_single_lightweight_simulation(players, timeline, seed);
+    if lightweight_run.total_hp_lost > starting_hp + 1e-6 {
+        return Err(format!(
+            "total_hp_lost {} exceeded starting party HP {}",
+            lightweight_run.total_hp_lost, starting_hp
+        ));
+    }
+    if !lightweight_run.final_score.is_finite() {
+        return Err(format!("lightweight final_score is not finite: {}", lightweight_run.final_score));
+    }
+
+    let (full_result, _events) = run_single_event_driven_simulation(players, timeline, seed, false);
+
+    let full_score = full_result.score.unwrap_or(f64::NAN);
+    if !full_score.is_finite() {
+        return Err(format!("full simulation score is not finite: {}", full_score));
+    }
+
+    let full_survivors = full_result
+        .encounters
+        .last()
+        .map(|enc| enc.rounds.last().unwrap().team1.iter().filter(|c| c.final_state.current_hp > 0).count())
+        .unwrap_or(0);
+
+    if lightweight_run.total_survivors != full_survivors {
+        return Err(format!(
+            "survivor count mismatch: lightweight={}, full={}",
+            lightweight_run.total_survivors, full_survivors
+        ));
+    }
+
+    Ok(())
+}
+
+fn buffer_fails(bytes: &[u8]) -> bool {
+    let mut stream = ByteStream::new(bytes);
+    let (players, timeline) = gen_scenario(&mut stream);
+    check_invariants(&players, &timeline, 0xC0FFEE).is_err()
+}
+
+/// Shrinks a failing buffer to a locally minimal one: repeatedly halves the buffer length
+/// while the shorter prefix still fails, then tries dropping individual bytes. Stops when
+/// neither move finds a smaller buffer that still reproduces the failure.
+fn shrink(mut bytes: Vec<u8>) -> Vec<u8> {
+    loop {
+        let mut made_progress = false;
+
+        let half = bytes.len() / 2;
+        if half > 0 {
+            let candidate = bytes[..half].to_vec();
+            if buffer_fails(&candidate) {
+                bytes = candidate;
+                made_progress = true;
+                continue;
+            }
+        }
+
+        for i in 0..bytes.len() {
+            let mut candidate = bytes.clone();
+            candidate.remove(i);
+            if buffer_fails(&candidate) {
+                bytes = candidate;
+                made_progress = true;
+                break;
+            }
+        }
+
+        if !made_progress {
+            break;
+        }
+    }
+    bytes
+}
+
+/// Writes the scenario a (minimized) failing buffer generates to `tests/scenarios` as a
+/// permanent JSON fixture, in the same `{ "players": ..., "encounters": ... }` shape
+/// `common::load_scenario` already understands.
+fn write_regression_fixture(bytes: &[u8], reason: &str) -> PathBuf {
+    let mut stream = ByteStream::new(bytes);
+    let (players, timeline) = gen_scenario(&mut stream);
+    let encounters: Vec<&Encounter> = timeline
+        .iter()
+        .filter_map(|step| match step {
+            TimelineStep::Combat(encounter) => Some(encounter),
+            TimelineStep::ShortRest(_) => None,
+        })
+        .collect();
+
+    let fixture = serde_json::json!({
+        "name": format!("generative regression: {}", reason),
+        "players": players,
+        "encounters": encounters,
+    });
+
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    let fixture_id = hasher.finish();
+
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/scenarios");
+    fs::create_dir_all(&path).expect("failed to create tests/scenarios");
+    path.push(format!("generative_regression_{:016x}.json", fixture_id));
+    fs::write(&path, serde_json::to_string_pretty(&fixture).unwrap()).expect("failed to write regression fixture");
+    path
+}
+
+/// Generates random scenarios from independent byte buffers and checks them against the
+/// invariants above; on the first failure, shrinks the buffer to a minimal counterexample and
+/// emits it as a fixture so the regression becomes permanent (see `write_regression_fixture`).
+#[test]
+fn property_generative_scenarios_hold_invariants() {
+    const NUM_CASES: usize = 200;
+    const BUFFER_LEN: usize = 64;
+
+    for case in 0..NUM_CASES {
+        let bytes = random_bytes(case as u64, BUFFER_LEN);
+        if buffer_fails(&bytes) {
+            let minimized = shrink(bytes);
+            let mut stream = ByteStream::new(&minimized);
+            let (players, timeline) = gen_scenario(&mut stream);
+            let reason = check_invariants(&players, &timeline, 0xC0FFEE)
+                .expect_err("buffer_fails said this buffer fails check_invariants");
+            let path = write_regression_fixture(&minimized, &reason);
+            panic!(
+                "generative scenario invariant violated (case {}): {} - minimized repro written to {:?}",
+                case, reason, path
+            );
+        }
+    }
+}
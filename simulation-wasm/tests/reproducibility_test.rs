@@ -1,7 +1,6 @@
 use simulation_wasm::model::{Creature, TimelineStep};
 use simulation_wasm::{run_single_lightweight_simulation, run_single_event_driven_simulation};
 use simulation_wasm::run_survey_pass;
-use simulation_wasm::rng;
 use std::fs;
 use std::path::PathBuf;
 
@@ -50,15 +49,7 @@ fn test_two_pass_reproducibility() {
         assert_eq!(lightweight_run.seed, expected_seed, "Seed mismatch at index {}", index);
 
         // 3. Re-run with Full Simulation (Phase 3 equivalent for single run)
-        // We must manually seed the RNG before calling this, or pass the seed if the function supported it.
-        // run_single_event_driven_simulation DOES NOT take a seed, it uses the global RNG.
-        // So we must seed it explicitly.
-        rng::seed_rng(expected_seed);
-        
-        let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, false);
-        
-        // Clear RNG after to be clean
-        rng::clear_rng();
+        let (full_result, _events) = run_single_event_driven_simulation(&players, &timeline, expected_seed, false);
 
         // 4. Compare Metrics
         
@@ -98,9 +89,7 @@ fn test_reproducibility_complex_mechanics() {
     for (index, lightweight_run) in survey_runs.iter().enumerate() {
         let expected_seed = base_seed + index as u64;
         
-        rng::seed_rng(expected_seed);
-        let (full_result, _) = run_single_event_driven_simulation(&players, &timeline, false);
-        rng::clear_rng();
+        let (full_result, _) = run_single_event_driven_simulation(&players, &timeline, expected_seed, false);
 
         let full_score = full_result.score.unwrap();
         